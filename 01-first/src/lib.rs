@@ -0,0 +1,161 @@
+pub mod report;
+pub mod stream;
+
+use common::{groups, parse_token, Answer, ParseError, Solution};
+use std::{cmp::Reverse, collections::BinaryHeap, error::Error, fmt::Display};
+
+/** how many of the elves carrying the most calories part 2 adds up */
+pub const TOP_ELVES: usize = 3;
+
+pub struct CalorieCounting;
+
+impl Solution for CalorieCounting {
+    /** calories carried by each elf, in input order */
+    type Input = Vec<i32>;
+
+    /** also checks there is an elf, part 2 has no answer with fewer than `TOP_ELVES` */
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let sums = parse_totals(input)?;
+        if sums.is_empty() {
+            return Err(ParseError::new(
+                input.lines().count() + 1,
+                1,
+                "the calories of at least one elf",
+                "",
+            ));
+        }
+        Ok(sums)
+    }
+
+    fn part1(sums: &Self::Input) -> Answer {
+        top_totals(sums, 1).expect("parsing checks there is an elf")[0].into()
+    }
+
+    fn part2(sums: &Self::Input) -> Answer {
+        match top_totals(sums, TOP_ELVES) {
+            Ok(top) => sum_totals(&top).into(),
+            Err(error) => Answer::Unavailable(error.to_string()),
+        }
+    }
+}
+
+/**
+ * calories of each item of each elf, in input order; elves are separated by a blank line,
+ * and each elf's total is checked to fit in `i32`, see [`elf_total`]
+ */
+pub fn parse_inventories(input: &str) -> Result<Vec<Vec<i32>>, ParseError> {
+    groups(input)
+        .map(|group| {
+            let mut total = 0;
+            group
+                .numbered_lines()
+                .map(|(line_number, line)| {
+                    let calories = parse_token(line_number, line, line, "a calorie count")?;
+                    total = add_calories(total, calories, line_number, line)?;
+                    Ok(calories)
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/** calories carried by each elf, in input order */
+pub fn parse_totals(input: &str) -> Result<Vec<i32>, ParseError> {
+    let elves = parse_inventories(input)?;
+    Ok(elves.iter().map(|items| elf_total(items)).collect())
+}
+
+/**
+ * an elf's running `total` plus the `calories` of the item on `line`,
+ * an error located on that line when the total no longer fits in `i32`
+ */
+pub fn add_calories(
+    total: i32,
+    calories: i32,
+    line_number: usize,
+    line: &str,
+) -> Result<i32, ParseError> {
+    total.checked_add(calories).ok_or_else(|| {
+        let expected = format!("calories keeping the elf's total within {}", i32::MAX);
+        ParseError::at_token(line_number, line, line, &expected)
+    })
+}
+
+/** total of the items of an elf from [`parse_inventories`], which checks it fits */
+pub fn elf_total(items: &[i32]) -> i32 {
+    items.iter().sum()
+}
+
+/** Asked for the top `requested` elves out of fewer `elves` */
+#[derive(Debug, PartialEq)]
+pub struct TooFewElves {
+    pub requested: usize,
+    pub elves: usize,
+}
+
+impl Display for TooFewElves {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "cannot take the top {} elves, there are only {}",
+            self.requested, self.elves
+        )
+    }
+}
+
+impl Error for TooFewElves {}
+
+/** The `n` highest totals pushed so far, in a min-heap that never holds more than `n` */
+pub struct TopTotals {
+    n: usize,
+    heap: BinaryHeap<Reverse<i32>>,
+    elves: usize,
+}
+
+impl TopTotals {
+    pub fn new(n: usize) -> Self {
+        TopTotals {
+            n,
+            heap: BinaryHeap::with_capacity(n + 1),
+            elves: 0,
+        }
+    }
+
+    pub fn push(&mut self, sum: i32) {
+        self.elves += 1;
+        self.heap.push(Reverse(sum));
+        if self.heap.len() > self.n {
+            self.heap.pop();
+        }
+    }
+
+    /** highest first, an error if fewer than `n` totals were pushed */
+    pub fn into_sorted(self) -> Result<Vec<i32>, TooFewElves> {
+        if self.n > self.elves {
+            return Err(TooFewElves {
+                requested: self.n,
+                elves: self.elves,
+            });
+        }
+        Ok(self
+            .heap
+            .into_sorted_vec()
+            .into_iter()
+            .map(|Reverse(sum)| sum)
+            .collect())
+    }
+}
+
+/** sum of elves' totals, in `i64` since a few totals that each fit in `i32` may add up past it */
+pub fn sum_totals(totals: &[i32]) -> i64 {
+    totals.iter().map(|&total| i64::from(total)).sum()
+}
+
+/** the `n` highest totals, highest first, without sorting them all */
+pub fn top_totals(sums: &[i32], n: usize) -> Result<Vec<i32>, TooFewElves> {
+    let mut top = TopTotals::new(n);
+    for &sum in sums {
+        top.push(sum);
+    }
+    top.into_sorted()
+}
@@ -1,28 +1,104 @@
-use std::fs::File;
-use std::io::{BufRead, BufReader, Error};
+use clap::{builder::RangedU64ValueParser, Parser, ValueEnum};
+use common::InputSource;
+use first::{
+    elf_total, parse_inventories, report, stream::stream_top_totals, sum_totals, top_totals,
+    TOP_ELVES,
+};
+use std::process;
 
-fn main() -> Result<(), Error> {
-    let path = "input";
+#[derive(Clone, Copy, ValueEnum)]
+enum ReportFormat {
+    /// A row per elf
+    Csv,
+    /// A row per summary statistic
+    SummaryCsv,
+    /// `{elves: [...], summary: {...}}`
+    Json,
+}
 
-    let input = File::open(path)?;
-    let buffered = BufReader::new(input);
+#[derive(Parser)]
+#[command(about = "Day 1: the top elf, then the total of the top N elves")]
+struct Cli {
+    /// Puzzle input file, `-` for stdin
+    #[arg(default_value = "input")]
+    input: String,
+    /// How many of the elves carrying the most calories to add up
+    #[arg(default_value_t = TOP_ELVES, value_parser = RangedU64ValueParser::<usize>::new().range(1..))]
+    top: usize,
+    /// Print every elf's inventory statistics and rank instead
+    #[arg(long, value_enum)]
+    report: Option<ReportFormat>,
+    /// Read the input a line at a time, keeping only the top N totals in memory
+    #[arg(long, conflicts_with = "report")]
+    stream: bool,
+}
 
-    let mut sum = 0;
-    let mut sums: Vec<i32> = Vec::new();
-    for line in buffered.lines() {
-        if let Ok(content) = line {
-            if !content.is_empty() {
-                sum += content.parse::<i32>().unwrap();
-            } else {
-                sums.push(sum);
-                sum = 0;
-            }
+fn main() {
+    let cli = Cli::parse();
+    if cli.stream {
+        stream(&cli);
+        return;
+    }
+    let input = match InputSource::from_arg(&cli.input).read() {
+        Ok(input) => input,
+        Err(error) => {
+            eprintln!("{}", error);
+            process::exit(1);
+        }
+    };
+    let elves = match parse_inventories(&input) {
+        Ok(elves) => elves,
+        Err(error) => {
+            eprintln!("{}", error.render(&cli.input, &input));
+            process::exit(1);
         }
+    };
+
+    if let Some(format) = cli.report {
+        let reports = report::elf_reports(&elves);
+        match format {
+            ReportFormat::Csv => print!("{}", report::to_csv(&reports)),
+            ReportFormat::SummaryCsv => match report::Summary::new(&reports) {
+                Some(summary) => print!("{}", summary.to_csv()),
+                None => {
+                    eprintln!("{}: no elves to summarize", cli.input);
+                    process::exit(1);
+                }
+            },
+            ReportFormat::Json => println!("{}", report::to_json(&reports)),
+        }
+        return;
     }
-    sums.sort();
-    sums.reverse();
-    let max = &sums[..3];
-    println!("{:?}", max.iter().sum::<i32>());
 
-    Ok(())
+    let sums: Vec<i32> = elves.iter().map(|items| elf_total(items)).collect();
+    let top_sums = match top_totals(&sums, cli.top) {
+        Ok(top_sums) => top_sums,
+        Err(error) => {
+            eprintln!("{}", error);
+            process::exit(1);
+        }
+    };
+    println!("{}", top_sums[0]);
+    println!("{}", sum_totals(&top_sums));
+}
+
+fn stream(cli: &Cli) {
+    let source = InputSource::from_arg(&cli.input);
+    let reader = match source.open() {
+        Ok(reader) => reader,
+        Err(error) => {
+            eprintln!("{}", error);
+            process::exit(1);
+        }
+    };
+    match stream_top_totals(reader, cli.top) {
+        Ok(top_sums) => {
+            println!("{}", top_sums[0]);
+            println!("{}", sum_totals(&top_sums));
+        }
+        Err(error) => {
+            eprintln!("{}: {}", source, error);
+            process::exit(1);
+        }
+    }
 }
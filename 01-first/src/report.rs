@@ -0,0 +1,155 @@
+use crate::elf_total;
+use serde_json::{json, Value};
+
+/** One elf's inventory, `rank` 1 carrying the most calories; ties share a rank */
+#[derive(Debug, Clone, PartialEq)]
+pub struct ElfReport {
+    /** 1-based position of the elf in the input */
+    pub elf: usize,
+    pub items: usize,
+    pub total: i32,
+    pub mean: f64,
+    pub median: f64,
+    pub rank: usize,
+}
+
+impl ElfReport {
+    pub fn to_json(&self) -> Value {
+        json!({
+            "elf": self.elf,
+            "items": self.items,
+            "total": self.total,
+            "mean": self.mean,
+            "median": self.median,
+            "rank": self.rank,
+        })
+    }
+}
+
+/** Statistics over the elves' totals, percentiles use the nearest rank */
+#[derive(Debug, Clone, PartialEq)]
+pub struct Summary {
+    pub elves: usize,
+    pub total: i64,
+    pub mean: f64,
+    pub min: i32,
+    pub p25: i32,
+    pub median: i32,
+    pub p75: i32,
+    pub p90: i32,
+    pub max: i32,
+}
+
+impl Summary {
+    /** `None` without any elf */
+    pub fn new(reports: &[ElfReport]) -> Option<Summary> {
+        let mut totals: Vec<i32> = reports.iter().map(|r| r.total).collect();
+        totals.sort_unstable();
+        let total = totals.iter().map(|&t| t as i64).sum::<i64>();
+        Some(Summary {
+            elves: totals.len(),
+            total,
+            mean: total as f64 / totals.len() as f64,
+            min: *totals.first()?,
+            p25: percentile(&totals, 25),
+            median: percentile(&totals, 50),
+            p75: percentile(&totals, 75),
+            p90: percentile(&totals, 90),
+            max: *totals.last()?,
+        })
+    }
+
+    /** a row per statistic under a `statistic,value` header */
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from("statistic,value\n");
+        csv.push_str(&format!("elves,{}\n", self.elves));
+        csv.push_str(&format!("total,{}\n", self.total));
+        csv.push_str(&format!("mean,{:.2}\n", self.mean));
+        for (name, value) in [
+            ("min", self.min),
+            ("p25", self.p25),
+            ("median", self.median),
+            ("p75", self.p75),
+            ("p90", self.p90),
+            ("max", self.max),
+        ] {
+            csv.push_str(&format!("{},{}\n", name, value));
+        }
+        csv
+    }
+
+    pub fn to_json(&self) -> Value {
+        json!({
+            "elves": self.elves,
+            "total": self.total,
+            "mean": self.mean,
+            "min": self.min,
+            "p25": self.p25,
+            "median": self.median,
+            "p75": self.p75,
+            "p90": self.p90,
+            "max": self.max,
+        })
+    }
+}
+
+/** `p`th percentile of non-empty ascending `sorted` values */
+fn percentile(sorted: &[i32], p: usize) -> i32 {
+    let rank = (p * sorted.len()).div_ceil(100).max(1);
+    sorted[rank - 1]
+}
+
+/** mean of the middle values of non-empty ascending `sorted` values */
+fn median(sorted: &[i32]) -> f64 {
+    let middle = sorted.len() / 2;
+    if sorted.len() % 2 == 1 {
+        sorted[middle] as f64
+    } else {
+        (sorted[middle - 1] as f64 + sorted[middle] as f64) / 2.0
+    }
+}
+
+/** one report per elf, in input order, from non-empty inventories checked by [`crate::parse_inventories`] */
+pub fn elf_reports(elves: &[Vec<i32>]) -> Vec<ElfReport> {
+    let totals: Vec<i32> = elves.iter().map(|items| elf_total(items)).collect();
+    let mut ascending = totals.clone();
+    ascending.sort_unstable();
+    elves
+        .iter()
+        .zip(&totals)
+        .enumerate()
+        .map(|(i, (items, &total))| {
+            let mut sorted = items.clone();
+            sorted.sort_unstable();
+            ElfReport {
+                elf: i + 1,
+                items: items.len(),
+                total,
+                mean: total as f64 / items.len() as f64,
+                median: median(&sorted),
+                rank: ascending.len() - ascending.partition_point(|&other| other <= total) + 1,
+            }
+        })
+        .collect()
+}
+
+/** a row per elf under an `elf,items,total,mean,median,rank` header */
+pub fn to_csv(reports: &[ElfReport]) -> String {
+    let mut csv = String::from("elf,items,total,mean,median,rank\n");
+    for r in reports {
+        csv.push_str(&format!(
+            "{},{},{},{:.2},{:.1},{}\n",
+            r.elf, r.items, r.total, r.mean, r.median, r.rank
+        ));
+    }
+    csv
+}
+
+/** `{elves: [...], summary: {...}}`, the summary is `null` without any elf */
+pub fn to_json(reports: &[ElfReport]) -> Value {
+    let elves: Vec<Value> = reports.iter().map(ElfReport::to_json).collect();
+    json!({
+        "elves": elves,
+        "summary": Summary::new(reports).map(|summary| summary.to_json()),
+    })
+}
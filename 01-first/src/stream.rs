@@ -0,0 +1,73 @@
+use crate::{add_calories, TooFewElves, TopTotals};
+use common::{parse_token, ParseError};
+use std::{error::Error, fmt::Display, io, io::BufRead};
+
+/** Why the top totals of a streamed inventory could not be found */
+#[derive(Debug)]
+pub enum StreamError {
+    Read(io::Error),
+    Parse(ParseError),
+    TooFewElves(TooFewElves),
+}
+
+impl Display for StreamError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StreamError::Read(error) => write!(f, "cannot read: {}", error),
+            StreamError::Parse(error) => {
+                write!(
+                    f,
+                    "line {}, column {}: {}",
+                    error.line,
+                    error.column,
+                    error.message()
+                )
+            }
+            StreamError::TooFewElves(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+impl Error for StreamError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            StreamError::Read(error) => Some(error),
+            StreamError::Parse(error) => Some(error),
+            StreamError::TooFewElves(error) => Some(error),
+        }
+    }
+}
+
+/**
+ * the `n` highest totals, highest first, reading `reader` a line at a time:
+ * only the current line and `n` totals are held, however long the inventory
+ */
+pub fn stream_top_totals(mut reader: impl BufRead, n: usize) -> Result<Vec<i32>, StreamError> {
+    let mut top = TopTotals::new(n);
+    let mut line = String::new();
+    let mut line_number = 0;
+    let mut sum: Option<i32> = None;
+    loop {
+        line.clear();
+        if reader.read_line(&mut line).map_err(StreamError::Read)? == 0 {
+            break;
+        }
+        line_number += 1;
+        let content = line.trim_end_matches(['\n', '\r']);
+        if content.is_empty() {
+            if let Some(sum) = sum.take() {
+                top.push(sum);
+            }
+        } else {
+            let calories = parse_token::<i32>(line_number, content, content, "a calorie count")
+                .map_err(StreamError::Parse)?;
+            let total = add_calories(sum.unwrap_or(0), calories, line_number, content)
+                .map_err(StreamError::Parse)?;
+            sum = Some(total);
+        }
+    }
+    if let Some(sum) = sum {
+        top.push(sum);
+    }
+    top.into_sorted().map_err(StreamError::TooFewElves)
+}
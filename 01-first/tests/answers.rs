@@ -0,0 +1,67 @@
+use common::{testing, Answer, Solution};
+use first::CalorieCounting;
+
+#[test]
+fn example() {
+    testing::assert_answers::<CalorieCounting>(include_str!("../example"), 24000, 45000);
+}
+
+#[test]
+fn puzzle_input() {
+    testing::assert_answers_file::<CalorieCounting>(env!("CARGO_MANIFEST_DIR"));
+}
+
+#[test]
+fn top_totals_highest_first() {
+    let sums = first::parse_totals(include_str!("../example")).unwrap();
+    assert_eq!(first::top_totals(&sums, 1), Ok(vec![24000]));
+    assert_eq!(
+        first::top_totals(&sums, 5),
+        Ok(vec![24000, 11000, 10000, 6000, 4000])
+    );
+}
+
+#[test]
+fn too_few_elves() {
+    assert_eq!(
+        first::top_totals(&[1000, 2000], 3),
+        Err(first::TooFewElves {
+            requested: 3,
+            elves: 2
+        })
+    );
+    let sums = CalorieCounting::parse("1000\n\n2000").unwrap();
+    assert_eq!(CalorieCounting::part1(&sums), 2000.into());
+    assert_eq!(
+        CalorieCounting::part2(&sums),
+        Answer::Unavailable("cannot take the top 3 elves, there are only 2".to_string())
+    );
+    let error = CalorieCounting::parse("").unwrap_err();
+    assert_eq!((error.line, error.column), (1, 1));
+}
+
+#[test]
+fn last_elf_without_trailing_blank_line() {
+    assert_eq!(
+        first::parse_totals("1000\n2000\n\n3000"),
+        Ok(vec![3000, 3000])
+    );
+    let error = first::parse_totals("1000\n\n20OO\n").unwrap_err();
+    assert_eq!((error.line, error.found.as_str()), (3, "20OO"));
+}
+
+#[test]
+fn top_totals_summed_past_i32() {
+    let input = "1000000000\n\n1000000000\n\n1000000000";
+    testing::assert_answers::<CalorieCounting>(input, 1000000000, 3000000000i64);
+    let top = first::stream::stream_top_totals(input.as_bytes(), 3).unwrap();
+    assert_eq!(first::sum_totals(&top), 3000000000);
+}
+
+#[test]
+fn elf_total_past_i32() {
+    let error = first::parse_inventories("1\n\n2147483647\n1").unwrap_err();
+    assert_eq!((error.line, error.found.as_str()), (4, "1"));
+    assert_eq!(CalorieCounting::parse("2147483647\n1").unwrap_err().line, 2);
+    assert_eq!(first::parse_totals("2147483646\n1"), Ok(vec![i32::MAX]));
+}
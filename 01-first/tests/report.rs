@@ -0,0 +1,50 @@
+use first::{parse_inventories, report};
+
+#[test]
+fn example_report() {
+    let elves = parse_inventories(include_str!("../example")).unwrap();
+    let reports = report::elf_reports(&elves);
+    let ranks: Vec<usize> = reports.iter().map(|r| r.rank).collect();
+    assert_eq!(ranks, vec![4, 5, 2, 1, 3]);
+    assert_eq!(reports[2].items, 2);
+    assert_eq!(reports[2].median, 5500.0);
+
+    let summary = report::Summary::new(&reports).unwrap();
+    assert_eq!((summary.elves, summary.total), (5, 55000));
+    assert_eq!(
+        (summary.min, summary.median, summary.max),
+        (4000, 10000, 24000)
+    );
+    assert_eq!(summary.mean, 11000.0);
+}
+
+#[test]
+fn ties_share_a_rank() {
+    let reports = report::elf_reports(&[vec![5], vec![3, 2], vec![1]]);
+    let ranks: Vec<usize> = reports.iter().map(|r| r.rank).collect();
+    assert_eq!(ranks, vec![1, 1, 3]);
+}
+
+#[test]
+fn csv_header_and_rows() {
+    let reports = report::elf_reports(&[vec![1000, 2000], vec![4000]]);
+    let csv = report::to_csv(&reports);
+    let mut lines = csv.lines();
+    assert_eq!(lines.next(), Some("elf,items,total,mean,median,rank"));
+    assert_eq!(lines.next(), Some("1,2,3000,1500.00,1500.0,2"));
+    assert_eq!(lines.next(), Some("2,1,4000,4000.00,4000.0,1"));
+    assert_eq!(lines.next(), None);
+    assert_eq!(report::Summary::new(&[]), None);
+}
+
+#[test]
+fn summary_csv() {
+    let reports = report::elf_reports(&[vec![1000, 2000], vec![4000]]);
+    let csv = report::Summary::new(&reports).unwrap().to_csv();
+    assert_eq!(
+        csv,
+        "statistic,value\nelves,2\ntotal,7000\nmean,3500.00\n\
+         min,3000\np25,3000\nmedian,3000\np75,4000\np90,4000\nmax,4000\n"
+    );
+    assert!(csv.lines().all(|line| line.split(',').count() == 2));
+}
@@ -0,0 +1,35 @@
+use first::stream::{stream_top_totals, StreamError};
+
+#[test]
+fn same_answers_as_the_solver() {
+    let example = include_bytes!("../example");
+    assert_eq!(
+        stream_top_totals(&example[..], 3).unwrap(),
+        vec![24000, 11000, 10000]
+    );
+}
+
+#[test]
+fn crlf_and_repeated_blank_lines() {
+    let input = "1000\r\n2000\r\n\r\n\r\n500\r\n";
+    assert_eq!(
+        stream_top_totals(input.as_bytes(), 2).unwrap(),
+        vec![3000, 500]
+    );
+}
+
+#[test]
+fn errors() {
+    match stream_top_totals("1\n\nx\n".as_bytes(), 1) {
+        Err(StreamError::Parse(error)) => assert_eq!(error.line, 3),
+        other => panic!("expected a parse error, got {:?}", other),
+    }
+    match stream_top_totals("1\n\n2147483000\n647\n1\n".as_bytes(), 1) {
+        Err(StreamError::Parse(error)) => assert_eq!((error.line, error.found.as_str()), (5, "1")),
+        other => panic!("expected an overflow error, got {:?}", other),
+    }
+    match stream_top_totals("1\n\n2".as_bytes(), 3) {
+        Err(StreamError::TooFewElves(error)) => assert_eq!(error.elves, 2),
+        other => panic!("expected too few elves, got {:?}", other),
+    }
+}
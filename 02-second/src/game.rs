@@ -0,0 +1,344 @@
+use crate::{scoring::ScoringRules, Interpretation, Outcome};
+use common::ParseError;
+use log::trace;
+use serde::Deserialize;
+use std::{error::Error, fmt::Display};
+
+/** A shape of a [`Game`], with its letters in the strategy guide */
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct GameShape {
+    pub name: String,
+    pub opponent: String,
+    pub player: String,
+    /** names of the shapes this one beats, derived from the shapes order when left out */
+    #[serde(default)]
+    pub beats: Option<Vec<String>>,
+}
+
+#[derive(Deserialize)]
+struct ShapeConfig {
+    #[serde(flatten)]
+    shape: GameShape,
+    score: i32,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct OutcomesConfig {
+    loss: i32,
+    draw: i32,
+    win: i32,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct GameConfig {
+    name: String,
+    outcomes: OutcomesConfig,
+    shapes: Vec<ShapeConfig>,
+}
+
+/** A round of a [`Game`], as indices into its shapes */
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Play {
+    pub opponent: usize,
+    pub played: usize,
+}
+
+/**
+ * A rock-paper-scissors like game over any number of shapes,
+ * where any two different shapes have a winner
+ */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Game {
+    pub name: String,
+    pub shapes: Vec<GameShape>,
+    rules: ScoringRules,
+    /** `wins[a][b]` when shape `a` beats shape `b` */
+    wins: Vec<Vec<bool>>,
+}
+
+/** Why a game definition was rejected */
+#[derive(Debug)]
+pub enum GameError {
+    Toml(toml::de::Error),
+    Invalid(String),
+}
+
+impl Display for GameError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GameError::Toml(error) => write!(f, "{}", error),
+            GameError::Invalid(reason) => write!(f, "{}", reason),
+        }
+    }
+}
+
+impl Error for GameError {}
+
+const BUILT_IN: [(&str, &str); 2] = [
+    ("rps", include_str!("../games/rps.toml")),
+    ("rpsls", include_str!("../games/rpsls.toml")),
+];
+
+impl Game {
+    /** names accepted by [`Game::built_in`] */
+    pub fn built_in_names() -> impl Iterator<Item = &'static str> {
+        BUILT_IN.iter().map(|(name, _)| *name)
+    }
+
+    pub fn built_in(name: &str) -> Option<Game> {
+        BUILT_IN
+            .iter()
+            .find(|(built_in, _)| *built_in == name)
+            .map(|(_, config)| Game::from_toml(config).expect("built-in games are valid"))
+    }
+
+    /** the puzzle's rock, paper and scissors */
+    pub fn classic() -> Game {
+        Game::built_in("rps").unwrap()
+    }
+
+    /** a game definition like `games/rps.toml` */
+    pub fn from_toml(content: &str) -> Result<Game, GameError> {
+        let config: GameConfig = toml::from_str(content).map_err(GameError::Toml)?;
+        let invalid = |reason: String| Err(GameError::Invalid(reason));
+        let rules = ScoringRules {
+            loss: config.outcomes.loss,
+            draw: config.outcomes.draw,
+            win: config.outcomes.win,
+            shapes: config
+                .shapes
+                .iter()
+                .map(|shape| (shape.shape.name.clone(), shape.score))
+                .collect(),
+        };
+        let shapes: Vec<GameShape> = config.shapes.into_iter().map(|shape| shape.shape).collect();
+        let count = shapes.len();
+        if count < 3 {
+            return invalid(format!("a game needs at least 3 shapes, found {}", count));
+        }
+        for (i, shape) in shapes.iter().enumerate() {
+            for letter in [&shape.opponent, &shape.player] {
+                if letter.is_empty() || letter.contains(char::is_whitespace) {
+                    return invalid(format!(
+                        "`{}` is not a valid letter for `{}`",
+                        letter, shape.name
+                    ));
+                }
+            }
+            if let Some(other) = shapes[..i].iter().find(|other| {
+                other.name == shape.name
+                    || other.opponent == shape.opponent
+                    || other.player == shape.player
+            }) {
+                return invalid(format!(
+                    "`{}` and `{}` share a name or a letter",
+                    other.name, shape.name
+                ));
+            }
+        }
+
+        let mut wins = vec![vec![false; count]; count];
+        if shapes.iter().all(|shape| shape.beats.is_none()) {
+            if count.is_multiple_of(2) {
+                return invalid(format!(
+                    "{} shapes cannot each beat half of the others, list what they `beats`",
+                    count
+                ));
+            }
+            for (a, row) in wins.iter_mut().enumerate() {
+                for distance in 1..=count / 2 {
+                    row[(a + count - distance) % count] = true;
+                }
+            }
+        } else {
+            for (a, shape) in shapes.iter().enumerate() {
+                let Some(beaten) = &shape.beats else {
+                    let reason = format!("`{}` does not list what it `beats`", shape.name);
+                    return invalid(reason);
+                };
+                for name in beaten {
+                    let Some(b) = shapes.iter().position(|other| &other.name == name) else {
+                        let reason = format!("`{}` beats unknown shape `{}`", shape.name, name);
+                        return invalid(reason);
+                    };
+                    wins[a][b] = true;
+                }
+            }
+            for a in 0..count {
+                for b in a..count {
+                    let (name_a, name_b) = (&shapes[a].name, &shapes[b].name);
+                    if a == b && wins[a][a] {
+                        return invalid(format!("`{}` cannot beat itself", name_a));
+                    }
+                    if a != b && wins[a][b] == wins[b][a] {
+                        return invalid(format!(
+                            "exactly one of `{}` and `{}` must beat the other",
+                            name_a, name_b
+                        ));
+                    }
+                }
+            }
+        }
+
+        Ok(Game {
+            name: config.name,
+            shapes,
+            rules,
+            wins,
+        })
+    }
+
+    /** the game's outcome and shape scores */
+    pub fn rules(&self) -> &ScoringRules {
+        &self.rules
+    }
+
+    /** this game scored with `rules`, shapes they leave out keep their score */
+    pub fn with_rules(mut self, rules: &ScoringRules) -> Result<Game, GameError> {
+        if let Some(name) = rules
+            .shapes
+            .keys()
+            .find(|&name| self.shapes.iter().all(|shape| &shape.name != name))
+        {
+            let reason = format!("{} has no `{}` shape to score", self.name, name);
+            return Err(GameError::Invalid(reason));
+        }
+        self.rules.loss = rules.loss;
+        self.rules.draw = rules.draw;
+        self.rules.win = rules.win;
+        self.rules.shapes.extend(rules.shapes.clone());
+        Ok(self)
+    }
+
+    /** outcome of playing shape `played` against shape `opponent` */
+    pub fn outcome(&self, played: usize, opponent: usize) -> Outcome {
+        if played == opponent {
+            Outcome::Draw
+        } else if self.wins[played][opponent] {
+            Outcome::Win
+        } else {
+            Outcome::Loss
+        }
+    }
+
+    /** points for playing the shape at index `shape` */
+    pub fn shape_score(&self, shape: usize) -> i32 {
+        self.rules.shape_score(&self.shapes[shape].name)
+    }
+
+    /** `i64` as a shape and an outcome score can add up past `i32::MAX` */
+    pub fn score(&self, play: Play) -> i64 {
+        let outcome = self.outcome(play.played, play.opponent);
+        self.shape_score(play.played) as i64 + self.rules.outcome_score(outcome) as i64
+    }
+
+    pub fn total_score(&self, plays: &[Play]) -> i64 {
+        let mut sum = 0;
+        for &play in plays {
+            sum += self.score(play);
+            trace!("{:?} {}", play, sum);
+        }
+        sum
+    }
+
+    /** the best scoring shape reaching `outcome` against `opponent`, if any does */
+    pub fn shape_for(&self, outcome: Outcome, opponent: usize) -> Option<usize> {
+        (0..self.shapes.len())
+            .filter(|&played| self.outcome(played, opponent) == outcome)
+            .max_by_key(|&played| self.shape_score(played))
+    }
+
+    /**
+     * each line of the guide as an opponent letter and a player letter,
+     * the latter being X, Y or Z for lose, draw or win with [`Interpretation::Outcomes`]
+     */
+    pub fn parse_guide(
+        &self,
+        input: &str,
+        interpretation: Interpretation,
+    ) -> Result<Vec<Play>, ParseError> {
+        let opponent_letters = self.letters(|shape| &shape.opponent);
+        let player_letters = match interpretation {
+            Interpretation::Shapes => self.letters(|shape| &shape.player),
+            Interpretation::Outcomes => "`X`, `Y` or `Z`".to_string(),
+        };
+        let mut plays = Vec::new();
+        for (i, line) in input.lines().enumerate() {
+            let line_number = i + 1;
+            let mut letters = line.split(' ');
+            let first = letters.next().unwrap_or("");
+            let Some(opponent) = self.shapes.iter().position(|s| s.opponent == first) else {
+                return Err(ParseError::at_token(
+                    line_number,
+                    line,
+                    first,
+                    &opponent_letters,
+                ));
+            };
+            let Some(second) = letters.next() else {
+                let expected = format!("a space then {}", player_letters);
+                return Err(ParseError::at_end_of_line(line_number, line, &expected));
+            };
+            let played = match interpretation {
+                Interpretation::Shapes => self.shapes.iter().position(|s| s.player == second),
+                Interpretation::Outcomes => {
+                    let outcome = match second {
+                        "X" => Outcome::Loss,
+                        "Y" => Outcome::Draw,
+                        "Z" => Outcome::Win,
+                        _ => {
+                            return Err(ParseError::at_token(
+                                line_number,
+                                line,
+                                second,
+                                &player_letters,
+                            ))
+                        }
+                    };
+                    let Some(played) = self.shape_for(outcome, opponent) else {
+                        let expected = format!(
+                            "an outcome reachable against `{}`",
+                            self.shapes[opponent].name
+                        );
+                        return Err(ParseError::at_token(line_number, line, second, &expected));
+                    };
+                    Some(played)
+                }
+            };
+            let Some(played) = played else {
+                return Err(ParseError::at_token(
+                    line_number,
+                    line,
+                    second,
+                    &player_letters,
+                ));
+            };
+            if let Some(found) = letters.next() {
+                return Err(ParseError::at_token(
+                    line_number,
+                    line,
+                    found,
+                    "end of line",
+                ));
+            }
+            plays.push(Play { opponent, played });
+        }
+        Ok(plays)
+    }
+
+    /** e.g. "`A`, `B` or `C`" */
+    fn letters(&self, letter: impl Fn(&GameShape) -> &String) -> String {
+        let letters: Vec<String> = self
+            .shapes
+            .iter()
+            .map(|shape| format!("`{}`", letter(shape)))
+            .collect();
+        match letters.split_last() {
+            Some((last, [])) => last.clone(),
+            Some((last, others)) => format!("{} or {}", others.join(", "), last),
+            None => String::new(),
+        }
+    }
+}
@@ -0,0 +1,65 @@
+pub mod game;
+pub mod scoring;
+pub mod tournament;
+
+use common::{Answer, ParseError, Solution};
+use game::{Game, Play};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    Loss,
+    Draw,
+    Win,
+}
+
+/** How the second column of the strategy guide is read */
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Interpretation {
+    /** X, Y and Z are the shapes to play: rock, paper and scissors */
+    Shapes,
+    /** X, Y and Z are the outcomes to reach: lose, draw and win */
+    Outcomes,
+}
+
+impl Interpretation {
+    pub const ALL: [Interpretation; 2] = [Interpretation::Shapes, Interpretation::Outcomes];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Interpretation::Shapes => "shapes",
+            Interpretation::Outcomes => "outcomes",
+        }
+    }
+}
+
+/** The plays of a strategy guide under both interpretations, and the game they are scored in */
+#[derive(Debug, Clone)]
+pub struct Guide {
+    pub game: Game,
+    pub shapes: Vec<Play>,
+    pub outcomes: Vec<Play>,
+}
+
+pub struct RockPaperScissors;
+
+impl Solution for RockPaperScissors {
+    /** the guide played in [`Game::classic`] */
+    type Input = Guide;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let game = Game::classic();
+        Ok(Guide {
+            shapes: game.parse_guide(input, Interpretation::Shapes)?,
+            outcomes: game.parse_guide(input, Interpretation::Outcomes)?,
+            game,
+        })
+    }
+
+    fn part1(guide: &Self::Input) -> Answer {
+        guide.game.total_score(&guide.shapes).into()
+    }
+
+    fn part2(guide: &Self::Input) -> Answer {
+        guide.game.total_score(&guide.outcomes).into()
+    }
+}
@@ -1,49 +1,194 @@
-use std::collections::HashMap;
-use std::fs::File;
-use std::io::{BufRead, BufReader};
+use clap::{Parser, ValueEnum};
+use common::InputSource;
+use rand::{rngs::StdRng, SeedableRng};
+use second::{
+    game::Game,
+    tournament::{max_score, parse_opponents, simulate, strategy_score, Strategy},
+    Interpretation,
+};
+use std::{fs, process};
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Reading {
+    /// X, Y and Z are rock, paper and scissors (part 1)
+    Shapes,
+    /// X, Y and Z are lose, draw and win (part 2)
+    Outcomes,
+    /// Both, one after the other
+    Both,
+}
+
+#[derive(Parser)]
+#[command(about = "Day 2: total score of following the strategy guide")]
+struct Cli {
+    /// Puzzle input file, `-` for stdin
+    #[arg(default_value = "input")]
+    input: String,
+    /// How to read the second column of the guide
+    #[arg(long, value_enum, default_value_t = Reading::Both)]
+    reading: Reading,
+    /// Built-in game (`rps` or `rpsls`) or game definition file, see `games/rps.toml`
+    #[arg(long, default_value = "rps")]
+    game: String,
+    /// Scoring rules file overriding the game's, e.g. `win = 10` and `[shapes]` `rock = 5`
+    #[arg(long)]
+    rules: Option<String>,
+    /// Points for a win, over the game and rules file
+    #[arg(long, allow_negative_numbers = true)]
+    win: Option<i32>,
+    /// Points for a draw, over the game and rules file
+    #[arg(long, allow_negative_numbers = true)]
+    draw: Option<i32>,
+    /// Points for a loss, over the game and rules file
+    #[arg(long, allow_negative_numbers = true)]
+    loss: Option<i32>,
+    /// Points for playing a shape, as `name=points`, over the game and rules file
+    #[arg(long, value_name = "NAME=POINTS", value_parser = parse_shape_score)]
+    shape_score: Vec<(String, i32)>,
+    /// Play the opponent's moves with other strategies instead of following the guide
+    #[arg(long)]
+    tournament: bool,
+    /// `random` or a shape name to only score that strategy in the tournament
+    #[arg(long, requires = "tournament")]
+    strategy: Option<String>,
+    /// How many random tournaments to simulate
+    #[arg(long, default_value_t = 1000, requires = "tournament")]
+    runs: usize,
+    /// Seed of the random strategy
+    #[arg(long, default_value_t = 0, requires = "tournament")]
+    seed: u64,
+}
+
+fn parse_shape_score(arg: &str) -> Result<(String, i32), String> {
+    let Some((name, points)) = arg.split_once('=') else {
+        return Err("expected `name=points`".to_string());
+    };
+    let points = points
+        .parse()
+        .map_err(|_| format!("`{}` is not a number of points", points))?;
+    Ok((name.to_string(), points))
+}
+
+/** the game's scoring rules, with the rules file then the command line ones over them */
+fn apply_rules(cli: &Cli, game: Game) -> Result<Game, String> {
+    let mut rules = game.rules().clone();
+    if let Some(path) = &cli.rules {
+        let content =
+            fs::read_to_string(path).map_err(|error| format!("cannot read {}: {}", path, error))?;
+        rules = rules
+            .merge_toml(&content)
+            .map_err(|error| format!("{}: {}", path, error))?;
+    }
+    rules.win = cli.win.unwrap_or(rules.win);
+    rules.draw = cli.draw.unwrap_or(rules.draw);
+    rules.loss = cli.loss.unwrap_or(rules.loss);
+    rules.shapes.extend(cli.shape_score.iter().cloned());
+    game.with_rules(&rules).map_err(|error| error.to_string())
+}
+
+fn load_game(arg: &str) -> Result<Game, String> {
+    if let Some(game) = Game::built_in(arg) {
+        return Ok(game);
+    }
+    let content = fs::read_to_string(arg).map_err(|error| {
+        let built_in: Vec<&str> = Game::built_in_names().collect();
+        format!(
+            "`{}` is neither a built-in game ({}) nor a readable file: {}",
+            arg,
+            built_in.join(", "),
+            error
+        )
+    })?;
+    Game::from_toml(&content).map_err(|error| format!("{}: {}", arg, error))
+}
 
 fn main() {
-    let lines = get_lines("input");
-
-    let move_scores: HashMap<&str, i32> = [("A", 1), ("B", 2), ("C", 3)].into_iter().collect();
-    let losing_moves: HashMap<&str, &str> =
-        [("A", "C"), ("B", "A"), ("C", "B")].into_iter().collect();
-    let winning_moves: HashMap<&str, &str> =
-        [("A", "B"), ("B", "C"), ("C", "A")].into_iter().collect();
-
-    let win_score = 6;
-    let draw_score = 3;
-    let mut sum = 0;
-
-    for line in lines {
-        let moves: Vec<&str> = line.split(" ").collect();
-        print!("{:?}", moves);
-        match moves[1] {
-            "X" => {
-                let strategic_move = losing_moves.get(moves[0]).unwrap();
-                sum += move_scores.get(strategic_move).unwrap();
-                println!("lose {:?} {}", strategic_move, sum);
-            }
-            "Y" => {
-                sum += move_scores.get(moves[0]).unwrap() + draw_score;
-                println!("draw {:?}", sum);
-            }
-            "Z" => {
-                let strategic_move = winning_moves.get(moves[0]).unwrap();
-                sum += move_scores.get(strategic_move).unwrap() + win_score;
-                println!("win {:?} {}", strategic_move, sum);
-            }
-            &_ => {
-                panic!("unexpected");
+    let cli = Cli::parse();
+    let input = match InputSource::from_arg(&cli.input).read() {
+        Ok(input) => input,
+        Err(error) => {
+            eprintln!("{}", error);
+            process::exit(1);
+        }
+    };
+    let game = match load_game(&cli.game).and_then(|game| apply_rules(&cli, game)) {
+        Ok(game) => game,
+        Err(error) => {
+            eprintln!("{}", error);
+            process::exit(1);
+        }
+    };
+    if cli.tournament {
+        if let Err(error) = tournament(&cli, &game, &input) {
+            eprintln!("{}", error);
+            process::exit(1);
+        }
+        return;
+    }
+    let interpretations: &[Interpretation] = match cli.reading {
+        Reading::Shapes => &[Interpretation::Shapes],
+        Reading::Outcomes => &[Interpretation::Outcomes],
+        Reading::Both => &Interpretation::ALL,
+    };
+    let mut success = true;
+    for &interpretation in interpretations {
+        match game.parse_guide(&input, interpretation) {
+            Ok(plays) => println!("{}: {}", interpretation.name(), game.total_score(&plays)),
+            Err(error) => {
+                eprintln!("{}", error.render(&cli.input, &input));
+                success = false;
             }
         }
     }
-    println!("{}", sum)
+    if !success {
+        process::exit(1);
+    }
 }
 
-fn get_lines(filename: &str) -> Vec<String> {
-    BufReader::new(File::open(filename).expect("file not found"))
-        .lines()
-        .map(|l| l.expect("error line read"))
-        .collect()
+/** the best score against the guide's opponent, then the score of other strategies */
+fn tournament(cli: &Cli, game: &Game, input: &str) -> Result<(), String> {
+    let opponents =
+        parse_opponents(game, input).map_err(|error| error.render(&cli.input, input))?;
+    println!("max: {}", max_score(game, &opponents));
+
+    let strategies: Vec<(&str, Strategy)> = match cli.strategy.as_deref() {
+        None => game
+            .shapes
+            .iter()
+            .enumerate()
+            .map(|(i, shape)| (shape.name.as_str(), Strategy::Fixed(i)))
+            .chain([("random", Strategy::Random)])
+            .collect(),
+        Some("random") => vec![("random", Strategy::Random)],
+        Some(name) => match game.shapes.iter().position(|shape| shape.name == name) {
+            Some(i) => vec![(name, Strategy::Fixed(i))],
+            None => {
+                let names: Vec<&str> = game.shapes.iter().map(|s| s.name.as_str()).collect();
+                return Err(format!(
+                    "`{}` is neither `random` nor a shape of {} ({})",
+                    name,
+                    game.name,
+                    names.join(", ")
+                ));
+            }
+        },
+    };
+    let mut rng = StdRng::seed_from_u64(cli.seed);
+    for &(name, strategy) in &strategies {
+        let score = strategy_score(game, &opponents, strategy, &mut rng);
+        println!("{}: {}", name, score);
+    }
+
+    if strategies
+        .iter()
+        .any(|&(_, strategy)| strategy == Strategy::Random)
+    {
+        if let Some(d) = simulate(game, &opponents, Strategy::Random, cli.runs, cli.seed) {
+            println!(
+                "{} random tournaments: min {}, p5 {}, median {}, mean {:.1}, p95 {}, max {}",
+                d.runs, d.min, d.p5, d.median, d.mean, d.p95, d.max
+            );
+        }
+    }
+    Ok(())
 }
@@ -0,0 +1,73 @@
+use crate::{game::GameError, Outcome};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+
+/** Points for each outcome of a round, plus points for the shape played */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScoringRules {
+    pub loss: i32,
+    pub draw: i32,
+    pub win: i32,
+    /** by shape name */
+    pub shapes: BTreeMap<String, i32>,
+}
+
+/** Rules file where every key is optional, see [`ScoringRules::merge_toml`] */
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RulesFile {
+    loss: Option<i32>,
+    draw: Option<i32>,
+    win: Option<i32>,
+    #[serde(default)]
+    shapes: BTreeMap<String, i32>,
+}
+
+impl ScoringRules {
+    /** the puzzle's rules: 0, 3 and 6 for a loss, a draw and a win, 1, 2 and 3 for the shapes */
+    pub fn puzzle() -> ScoringRules {
+        ScoringRules {
+            loss: 0,
+            draw: 3,
+            win: 6,
+            shapes: [("rock", 1), ("paper", 2), ("scissors", 3)]
+                .into_iter()
+                .map(|(name, score)| (name.to_string(), score))
+                .collect(),
+        }
+    }
+
+    pub fn outcome_score(&self, outcome: Outcome) -> i32 {
+        match outcome {
+            Outcome::Loss => self.loss,
+            Outcome::Draw => self.draw,
+            Outcome::Win => self.win,
+        }
+    }
+
+    /** 0 for shapes without a score */
+    pub fn shape_score(&self, name: &str) -> i32 {
+        self.shapes.get(name).copied().unwrap_or(0)
+    }
+
+    /**
+     * these rules with the ones set in `content`, e.g.
+     *
+     * ```toml
+     * win = 10
+     * loss = -1
+     *
+     * [shapes]
+     * rock = 5
+     * ```
+     */
+    pub fn merge_toml(&self, content: &str) -> Result<ScoringRules, GameError> {
+        let file: RulesFile = toml::from_str(content).map_err(GameError::Toml)?;
+        let mut rules = self.clone();
+        rules.loss = file.loss.unwrap_or(rules.loss);
+        rules.draw = file.draw.unwrap_or(rules.draw);
+        rules.win = file.win.unwrap_or(rules.win);
+        rules.shapes.extend(file.shapes);
+        Ok(rules)
+    }
+}
@@ -0,0 +1,110 @@
+use crate::game::{Game, Play};
+use common::ParseError;
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+/** How we pick our shape against each of the opponent's moves */
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Strategy {
+    /** always the shape at this index of the game */
+    Fixed(usize),
+    /** any shape, uniformly */
+    Random,
+}
+
+/** Scores of many tournaments, percentiles use the nearest rank */
+#[derive(Debug, Clone, PartialEq)]
+pub struct Distribution {
+    pub runs: usize,
+    pub min: i64,
+    pub p5: i64,
+    pub median: i64,
+    pub mean: f64,
+    pub p95: i64,
+    pub max: i64,
+}
+
+/** the opponent's shape on each line of the guide, ignoring our column */
+pub fn parse_opponents(game: &Game, input: &str) -> Result<Vec<usize>, ParseError> {
+    let mut opponents = Vec::new();
+    for (i, line) in input.lines().enumerate() {
+        let first = line.split(' ').next().unwrap_or("");
+        let Some(opponent) = game.shapes.iter().position(|s| s.opponent == first) else {
+            let letters: Vec<String> = game
+                .shapes
+                .iter()
+                .map(|s| format!("`{}`", s.opponent))
+                .collect();
+            let expected = format!("one of {}", letters.join(", "));
+            return Err(ParseError::at_token(i + 1, line, first, &expected));
+        };
+        opponents.push(opponent);
+    }
+    Ok(opponents)
+}
+
+/** the best scoring shape against each move, for the highest achievable score */
+pub fn optimal_plays(game: &Game, opponents: &[usize]) -> Vec<Play> {
+    opponents
+        .iter()
+        .map(|&opponent| {
+            (0..game.shapes.len())
+                .map(|played| Play { opponent, played })
+                .max_by_key(|&play| game.score(play))
+                .expect("games have shapes")
+        })
+        .collect()
+}
+
+pub fn max_score(game: &Game, opponents: &[usize]) -> i64 {
+    game.total_score(&optimal_plays(game, opponents))
+}
+
+/** total score of one tournament, `rng` is only drawn from by [`Strategy::Random`] */
+pub fn strategy_score(
+    game: &Game,
+    opponents: &[usize],
+    strategy: Strategy,
+    rng: &mut StdRng,
+) -> i64 {
+    opponents
+        .iter()
+        .map(|&opponent| {
+            let played = match strategy {
+                Strategy::Fixed(played) => played,
+                Strategy::Random => rng.gen_range(0..game.shapes.len()),
+            };
+            game.score(Play { opponent, played })
+        })
+        .sum()
+}
+
+/**
+ * scores of `runs` tournaments played with `strategy`, always the same for the same `seed`;
+ * `None` without any run
+ */
+pub fn simulate(
+    game: &Game,
+    opponents: &[usize],
+    strategy: Strategy,
+    runs: usize,
+    seed: u64,
+) -> Option<Distribution> {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut scores: Vec<i64> = (0..runs)
+        .map(|_| strategy_score(game, opponents, strategy, &mut rng))
+        .collect();
+    if scores.is_empty() {
+        return None;
+    }
+    scores.sort_unstable();
+    let percentile = |p: usize| scores[(p * scores.len()).div_ceil(100).max(1) - 1];
+    Some(Distribution {
+        runs,
+        min: scores[0],
+        p5: percentile(5),
+        median: percentile(50),
+        mean: scores.iter().map(|&s| s as f64).sum::<f64>() / runs as f64,
+        p95: percentile(95),
+        max: scores[runs - 1],
+    })
+}
@@ -0,0 +1,12 @@
+use common::testing;
+use second::RockPaperScissors;
+
+#[test]
+fn example() {
+    testing::assert_answers::<RockPaperScissors>(include_str!("../example"), 15, 12);
+}
+
+#[test]
+fn puzzle_input() {
+    testing::assert_answers_file::<RockPaperScissors>(env!("CARGO_MANIFEST_DIR"));
+}
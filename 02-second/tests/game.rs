@@ -0,0 +1,103 @@
+use common::Solution;
+use second::{
+    game::{Game, Play},
+    Interpretation, Outcome, RockPaperScissors,
+};
+
+#[test]
+fn classic_game_matches_the_solver() {
+    let input = include_str!("../example");
+    let game = Game::classic();
+    let shapes = game.parse_guide(input, Interpretation::Shapes).unwrap();
+    let outcomes = game.parse_guide(input, Interpretation::Outcomes).unwrap();
+    let guide = RockPaperScissors::parse(input).unwrap();
+    assert_eq!(
+        RockPaperScissors::part1(&guide),
+        game.total_score(&shapes).into()
+    );
+    assert_eq!(
+        RockPaperScissors::part2(&guide),
+        game.total_score(&outcomes).into()
+    );
+}
+
+#[test]
+fn rpsls_rules() {
+    let game = Game::built_in("rpsls").unwrap();
+    let shape = |name: &str| game.shapes.iter().position(|s| s.name == name).unwrap();
+    for (winner, loser) in [
+        ("scissors", "paper"),
+        ("paper", "rock"),
+        ("rock", "lizard"),
+        ("lizard", "spock"),
+        ("spock", "scissors"),
+        ("scissors", "lizard"),
+        ("lizard", "paper"),
+        ("paper", "spock"),
+        ("spock", "rock"),
+        ("rock", "scissors"),
+    ] {
+        assert_eq!(game.outcome(shape(winner), shape(loser)), Outcome::Win);
+        assert_eq!(game.outcome(shape(loser), shape(winner)), Outcome::Loss);
+    }
+    let plays = game
+        .parse_guide("A V\nE Z\nC W", Interpretation::Shapes)
+        .unwrap();
+    assert_eq!(
+        plays[2],
+        Play {
+            opponent: shape("paper"),
+            played: shape("spock")
+        }
+    );
+    assert_eq!(game.total_score(&plays), (1 + 3) + (5 + 3) + 2);
+}
+
+#[test]
+fn explicit_beats() {
+    let config = r#"
+        name = "reversed"
+        outcomes = { loss = 0, draw = 3, win = 6 }
+        shapes = [
+            { name = "rock", opponent = "A", player = "X", score = 1, beats = ["paper"] },
+            { name = "paper", opponent = "B", player = "Y", score = 2, beats = ["scissors"] },
+            { name = "scissors", opponent = "C", player = "Z", score = 3, beats = ["rock"] },
+        ]
+    "#;
+    let game = Game::from_toml(config).unwrap();
+    assert_eq!(game.outcome(0, 1), Outcome::Win);
+    let plays = game.parse_guide("A Z", Interpretation::Outcomes).unwrap();
+    assert_eq!(plays[0].played, 2);
+}
+
+#[test]
+fn invalid_games() {
+    let shapes = |beats: &str| {
+        format!(
+            r#"
+            name = "invalid"
+            outcomes = {{ loss = 0, draw = 3, win = 6 }}
+            shapes = [
+                {{ name = "a", opponent = "A", player = "X", score = 1, beats = ["b"] }},
+                {{ name = "b", opponent = "B", player = "Y", score = 2, beats = [{}] }},
+                {{ name = "c", opponent = "C", player = "Z", score = 3, beats = ["a", "b"] }},
+            ]
+            "#,
+            beats
+        )
+    };
+    assert!(Game::from_toml(&shapes("")).is_ok());
+    assert!(Game::from_toml(&shapes(r#""a""#)).is_err());
+    assert!(Game::from_toml(&shapes(r#""d""#)).is_err());
+    assert!(Game::from_toml("name = 1").is_err());
+}
+
+#[test]
+fn guide_errors_are_located() {
+    let game = Game::classic();
+    let error = game
+        .parse_guide("A X\nB W", Interpretation::Shapes)
+        .unwrap_err();
+    assert_eq!((error.line, error.column), (2, 3));
+    assert_eq!(error.expected, "`X`, `Y` or `Z`");
+}
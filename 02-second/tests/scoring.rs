@@ -0,0 +1,52 @@
+use second::{
+    game::Game,
+    scoring::ScoringRules,
+    tournament::{max_score, parse_opponents, simulate, Strategy},
+    Interpretation,
+};
+
+#[test]
+fn puzzle_rules_are_the_classic_game_rules() {
+    assert_eq!(Game::classic().rules(), &ScoringRules::puzzle());
+}
+
+#[test]
+fn house_rules() {
+    let rules = ScoringRules::puzzle()
+        .merge_toml("win = 10\nloss = -1\n[shapes]\nrock = 5")
+        .unwrap();
+    assert_eq!((rules.loss, rules.draw, rules.win), (-1, 3, 10));
+    assert_eq!(rules.shape_score("rock"), 5);
+    assert_eq!(rules.shape_score("paper"), 2);
+
+    let input = include_str!("../example");
+    let game = Game::classic().with_rules(&rules).unwrap();
+    let shapes = game.parse_guide(input, Interpretation::Shapes).unwrap();
+    assert_eq!(game.total_score(&shapes), (2 + 10) + (5 - 1) + (3 + 3));
+    let outcomes = game.parse_guide(input, Interpretation::Outcomes).unwrap();
+    assert_eq!(game.total_score(&outcomes), (5 + 3) + (5 - 1) + (5 + 10));
+}
+
+#[test]
+fn invalid_rules() {
+    assert!(ScoringRules::puzzle().merge_toml("wins = 1").is_err());
+    let mut rules = ScoringRules::puzzle();
+    rules.shapes.insert("lizard".to_string(), 4);
+    assert!(Game::classic().with_rules(&rules).is_err());
+}
+
+#[test]
+fn scores_past_i32() {
+    let rules = ScoringRules::puzzle()
+        .merge_toml("win = 2147483647")
+        .unwrap();
+    let game = Game::classic().with_rules(&rules).unwrap();
+    let shapes = game
+        .parse_guide("A Y\nA Y", Interpretation::Shapes)
+        .unwrap();
+    assert_eq!(game.total_score(&shapes), 2 * (2 + 2147483647));
+    let opponents = parse_opponents(&game, "A Y\nA Y").unwrap();
+    assert_eq!(max_score(&game, &opponents), 2 * (2 + 2147483647));
+    let paper = simulate(&game, &opponents, Strategy::Fixed(1), 1, 0).unwrap();
+    assert_eq!(paper.max, 2 * (2 + 2147483647));
+}
@@ -0,0 +1,58 @@
+use second::{
+    game::Game,
+    tournament::{max_score, optimal_plays, parse_opponents, simulate, Strategy},
+};
+use std::process::Command;
+
+#[test]
+fn max_score_always_wins_classic() {
+    let game = Game::classic();
+    let opponents = parse_opponents(&game, include_str!("../example")).unwrap();
+    assert_eq!(opponents, vec![0, 1, 2]);
+    let plays = optimal_plays(&game, &opponents);
+    assert!(plays
+        .iter()
+        .all(|&play| game.outcome(play.played, play.opponent) == second::Outcome::Win));
+    assert_eq!(max_score(&game, &opponents), (2 + 6) + (3 + 6) + (1 + 6));
+}
+
+#[test]
+fn fixed_strategy_does_not_vary() {
+    let game = Game::classic();
+    let opponents = parse_opponents(&game, include_str!("../example")).unwrap();
+    let rock = simulate(&game, &opponents, Strategy::Fixed(0), 10, 1).unwrap();
+    assert_eq!((rock.min, rock.max), (12, 12));
+}
+
+#[test]
+fn seeded_simulations_repeat() {
+    let game = Game::built_in("rpsls").unwrap();
+    let opponents = parse_opponents(&game, "A V\nB W\nC X\nD Y\nE Z").unwrap();
+    let first = simulate(&game, &opponents, Strategy::Random, 200, 42).unwrap();
+    assert_eq!(
+        first,
+        simulate(&game, &opponents, Strategy::Random, 200, 42).unwrap()
+    );
+    assert!(first.min <= first.median && first.median <= first.max);
+    assert!(first.max <= max_score(&game, &opponents));
+    assert_eq!(simulate(&game, &opponents, Strategy::Random, 0, 42), None);
+}
+
+#[test]
+fn tournament_output() {
+    let example = concat!(env!("CARGO_MANIFEST_DIR"), "/example");
+    let output = Command::new(env!("CARGO_BIN_EXE_second"))
+        .args([example, "--tournament", "--runs", "10"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let lines: Vec<&str> = stdout.lines().collect();
+    assert_eq!(
+        lines[..4],
+        ["max: 24", "rock: 12", "paper: 15", "scissors: 18"]
+    );
+    assert!(lines[4].starts_with("random: "));
+    assert!(lines[5].starts_with("10 random tournaments: "));
+    assert_eq!(lines.len(), 6);
+}
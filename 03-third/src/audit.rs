@@ -0,0 +1,178 @@
+use crate::{group_badges, item_set::ItemSet, shared_items, Rucksack};
+use serde_json::{json, Value};
+use std::ops::Range;
+
+/** What an item set found in a rucksack or a group says about it */
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /** exactly one item, as the puzzle expects */
+    Ok,
+    None,
+    Multiple,
+    /** a group with fewer rucksacks than the group size */
+    Incomplete,
+}
+
+impl Status {
+    fn of(items: ItemSet) -> Status {
+        match items.len() {
+            0 => Status::None,
+            1 => Status::Ok,
+            _ => Status::Multiple,
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Status::Ok => "ok",
+            Status::None => "none",
+            Status::Multiple => "multiple",
+            Status::Incomplete => "incomplete",
+        }
+    }
+}
+
+/** Items found in both compartments of the rucksack on the 1-based `line` */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RucksackAudit {
+    pub line: usize,
+    pub shared: ItemSet,
+    pub status: Status,
+}
+
+/** Badge candidates of the group made of the 1-based `lines` */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GroupAudit {
+    pub group: usize,
+    pub lines: Range<usize>,
+    pub badges: ItemSet,
+    pub status: Status,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Audit {
+    pub rucksacks: Vec<RucksackAudit>,
+    pub groups: Vec<GroupAudit>,
+}
+
+impl Audit {
+    /** every rucksack, and every consecutive group of `group_size`, the last may be incomplete */
+    pub fn new(lines: &[Rucksack], group_size: usize) -> Audit {
+        assert!(group_size > 0, "groups hold at least one rucksack");
+        let rucksacks = lines
+            .iter()
+            .enumerate()
+            .map(|(i, line)| {
+                let shared = shared_items(line);
+                RucksackAudit {
+                    line: i + 1,
+                    shared,
+                    status: Status::of(shared),
+                }
+            })
+            .collect();
+        let groups = lines
+            .chunks(group_size)
+            .enumerate()
+            .map(|(i, group)| {
+                let first_line = i * group_size + 1;
+                let badges = group_badges(group);
+                GroupAudit {
+                    group: i + 1,
+                    lines: first_line..first_line + group.len(),
+                    badges,
+                    status: if group.len() < group_size {
+                        Status::Incomplete
+                    } else {
+                        Status::of(badges)
+                    },
+                }
+            })
+            .collect();
+        Audit { rucksacks, groups }
+    }
+
+    /** rucksacks and groups that are not [`Status::Ok`] */
+    pub fn flagged(&self) -> (usize, usize) {
+        (
+            self.rucksacks
+                .iter()
+                .filter(|r| r.status != Status::Ok)
+                .count(),
+            self.groups
+                .iter()
+                .filter(|g| g.status != Status::Ok)
+                .count(),
+        )
+    }
+
+    pub fn to_json(&self) -> Value {
+        let rucksacks: Vec<Value> = self
+            .rucksacks
+            .iter()
+            .map(|r| {
+                json!({
+                    "line": r.line,
+                    "shared": r.shared.to_string(),
+                    "status": r.status.name(),
+                })
+            })
+            .collect();
+        let groups: Vec<Value> = self
+            .groups
+            .iter()
+            .map(|g| {
+                json!({
+                    "group": g.group,
+                    "first_line": g.lines.start,
+                    "last_line": g.lines.end - 1,
+                    "badges": g.badges.to_string(),
+                    "status": g.status.name(),
+                })
+            })
+            .collect();
+        let (flagged_rucksacks, flagged_groups) = self.flagged();
+        json!({
+            "rucksacks": rucksacks,
+            "groups": groups,
+            "flagged_rucksacks": flagged_rucksacks,
+            "flagged_groups": flagged_groups,
+        })
+    }
+
+    /** a table of the rucksacks, one of the groups, then how many of them are flagged */
+    pub fn to_table(&self) -> String {
+        let mut table = format!("{:>5}  {:<12}  {}\n", "line", "shared", "status");
+        for r in &self.rucksacks {
+            table.push_str(&format!(
+                "{:>5}  {:<12}  {}\n",
+                r.line,
+                r.shared.to_string(),
+                r.status.name()
+            ));
+        }
+        table.push_str(&format!(
+            "\n{:>5}  {:<11}  {:<12}  {}\n",
+            "group", "lines", "badges", "status"
+        ));
+        for g in &self.groups {
+            let lines = format!("{}-{}", g.lines.start, g.lines.end - 1);
+            table.push_str(&format!(
+                "{:>5}  {:<11}  {:<12}  {}\n",
+                g.group,
+                lines,
+                g.badges.to_string(),
+                g.status.name()
+            ));
+        }
+        let (flagged_rucksacks, flagged_groups) = self.flagged();
+        table.push_str(&format!(
+            "\nflagged: {} of {} rucksacks, {} of {} groups\n",
+            flagged_rucksacks,
+            self.rucksacks.len(),
+            flagged_groups,
+            self.groups.len()
+        ));
+        table
+    }
+}
@@ -0,0 +1,135 @@
+use crate::Priority;
+use rand::{rngs::StdRng, seq::SliceRandom, Rng, SeedableRng};
+use std::{error::Error, fmt::Display};
+
+/** What to generate, the same `seed` always giving the same input */
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GeneratorConfig {
+    pub seed: u64,
+    pub rucksacks: usize,
+    /** items per rucksack, even */
+    pub line_length: usize,
+    pub group_size: usize,
+}
+
+/** A valid puzzle input and its answers */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Generated {
+    pub input: String,
+    pub part1: u32,
+    pub part2: u32,
+}
+
+impl Generated {
+    /** the answers in the format of the days' `answers.toml` */
+    pub fn answers_toml(&self) -> String {
+        format!("part1 = {}\npart2 = {}\n", self.part1, self.part2)
+    }
+}
+
+/** A [`GeneratorConfig`] no valid input matches */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidConfig(pub String);
+
+impl Display for InvalidConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl Error for InvalidConfig {}
+
+/**
+ * rucksacks whose compartments share exactly one item, in groups whose rucksacks
+ * share exactly one badge: every other item is left out of one rucksack of the group
+ */
+pub fn generate(config: &GeneratorConfig) -> Result<Generated, InvalidConfig> {
+    if config.group_size == 0 || !config.rucksacks.is_multiple_of(config.group_size) {
+        return Err(InvalidConfig(format!(
+            "{} rucksacks cannot make groups of {}",
+            config.rucksacks, config.group_size
+        )));
+    }
+    if config.line_length < 2 || config.line_length % 2 == 1 {
+        return Err(InvalidConfig(format!(
+            "rucksacks hold an even number of items, at least 2, not {}",
+            config.line_length
+        )));
+    }
+
+    let mut rng = StdRng::seed_from_u64(config.seed);
+    let items: Vec<Priority> = (1..=52).map(Priority::from_value).collect();
+    let mut generated = Generated {
+        input: String::new(),
+        part1: 0,
+        part2: 0,
+    };
+    for _ in 0..config.rucksacks / config.group_size {
+        let badge = *items.choose(&mut rng).unwrap();
+        generated.part2 += badge.value();
+        // items[i] never is in the rucksack left_out[i] of the group
+        let left_out: Vec<usize> = items
+            .iter()
+            .map(|_| rng.gen_range(0..config.group_size))
+            .collect();
+        for rucksack in 0..config.group_size {
+            let allowed: Vec<Priority> = items
+                .iter()
+                .zip(&left_out)
+                .filter(|&(&item, &left_out)| item == badge || left_out != rucksack)
+                .map(|(&item, _)| item)
+                .collect();
+            let (shared, line) = generate_rucksack(&mut rng, &allowed, badge, config.line_length);
+            generated.part1 += shared.value();
+            if !generated.input.is_empty() {
+                generated.input.push('\n');
+            }
+            generated.input.push_str(&line);
+        }
+    }
+    Ok(generated)
+}
+
+/** a rucksack of `allowed` items holding `badge`, and the item its compartments share */
+fn generate_rucksack(
+    rng: &mut StdRng,
+    allowed: &[Priority],
+    badge: Priority,
+    length: usize,
+) -> (Priority, String) {
+    let half = length / 2;
+    // with one item per compartment, the badge has to be the shared item
+    let shared = if half == 1 {
+        badge
+    } else {
+        *allowed.choose(rng).unwrap()
+    };
+    let mut first_only = Vec::new();
+    let mut second_only = Vec::new();
+    for &item in allowed.iter().filter(|&&item| item != shared) {
+        match rng.gen_range(0..3) {
+            0 => first_only.push(item),
+            1 => second_only.push(item),
+            _ => (),
+        }
+    }
+    if badge != shared && !first_only.contains(&badge) && !second_only.contains(&badge) {
+        first_only.push(badge);
+    }
+
+    let mut line = String::with_capacity(length);
+    for compartment in [&first_only, &second_only] {
+        let mut content = vec![shared];
+        if badge != shared && compartment.contains(&badge) {
+            content.push(badge);
+        }
+        let mut pool = compartment.clone();
+        pool.push(shared);
+        while content.len() < half {
+            content.push(*pool.choose(rng).unwrap());
+        }
+        content.shuffle(rng);
+        line.extend(content.iter().map(|item| item.item()));
+    }
+    (shared, line)
+}
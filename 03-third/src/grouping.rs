@@ -0,0 +1,100 @@
+use crate::{group_badges, item_set::ItemSet, Rucksack};
+use std::{error::Error, fmt::Display, ops::Range, ops::RangeInclusive};
+
+/** Rucksacks after the last full group, from the 1-based line `first_line` */
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LeftoverLines {
+    pub group_size: usize,
+    pub first_line: usize,
+    pub count: usize,
+}
+
+impl Display for LeftoverLines {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} rucksack(s) from line {} do not make a full group of {}",
+            self.count, self.first_line, self.group_size
+        )
+    }
+}
+
+impl Error for LeftoverLines {}
+
+/** consecutive groups of `size` rucksacks, an error if the last one is not full */
+pub fn full_groups(lines: &[Rucksack], size: usize) -> Result<Vec<&[Rucksack]>, LeftoverLines> {
+    assert!(size > 0, "groups hold at least one rucksack");
+    let chunks = lines.chunks_exact(size);
+    let count = chunks.remainder().len();
+    if count > 0 {
+        return Err(LeftoverLines {
+            group_size: size,
+            first_line: lines.len() - count + 1,
+            count,
+        });
+    }
+    Ok(chunks.collect())
+}
+
+/** priorities of the badges of each group of `size` consecutive rucksacks */
+pub fn group_prio_sum(lines: &[Rucksack], size: usize) -> Result<u32, LeftoverLines> {
+    Ok(full_groups(lines, size)?
+        .into_iter()
+        .map(|group| group_badges(group).priority_sum())
+        .sum())
+}
+
+/** `size` consecutive rucksacks and the items they all hold */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Window {
+    /** indices of the rucksacks */
+    pub lines: Range<usize>,
+    pub badges: ItemSet,
+}
+
+/** every run of `size` consecutive rucksacks sharing exactly one item */
+pub fn single_badge_windows(lines: &[Rucksack], size: usize) -> Vec<Window> {
+    assert!(size > 0, "groups hold at least one rucksack");
+    lines
+        .windows(size)
+        .enumerate()
+        .map(|(start, window)| Window {
+            lines: start..start + size,
+            badges: group_badges(window),
+        })
+        .filter(|window| window.badges.len() == 1)
+        .collect()
+}
+
+/**
+ * a split of all rucksacks into consecutive groups, of sizes in `sizes`,
+ * where each group shares exactly one item; `None` when there is no such split
+ */
+pub fn find_partition(lines: &[Rucksack], sizes: RangeInclusive<usize>) -> Option<Vec<Window>> {
+    assert!(*sizes.start() > 0, "groups hold at least one rucksack");
+    // previous[end] is the start of the last group of a valid split of lines[..end]
+    let mut previous: Vec<Option<usize>> = vec![None; lines.len() + 1];
+    for end in 1..=lines.len() {
+        previous[end] = sizes
+            .clone()
+            .filter(|&size| size <= end)
+            .map(|size| end - size)
+            .find(|&start| {
+                (start == 0 || previous[start].is_some())
+                    && group_badges(&lines[start..end]).len() == 1
+            });
+    }
+
+    let mut groups = Vec::new();
+    let mut end = lines.len();
+    while end > 0 {
+        let start = previous[end]?;
+        groups.push(Window {
+            lines: start..end,
+            badges: group_badges(&lines[start..end]),
+        });
+        end = start;
+    }
+    groups.reverse();
+    Some(groups)
+}
@@ -0,0 +1,57 @@
+use crate::Priority;
+use std::fmt::Display;
+
+/** Set of item types, bit `p - 1` standing for the item of priority `p` */
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ItemSet(u64);
+
+impl ItemSet {
+    pub const EMPTY: ItemSet = ItemSet(0);
+    /** every item type, the neutral set of [`ItemSet::intersection`] */
+    pub const ALL: ItemSet = ItemSet((1 << 52) - 1);
+
+    pub fn with(self, priority: Priority) -> ItemSet {
+        ItemSet(self.0 | 1 << (priority.value() - 1))
+    }
+
+    pub fn contains(self, priority: Priority) -> bool {
+        self.0 & 1 << (priority.value() - 1) != 0
+    }
+
+    pub fn intersection(self, other: ItemSet) -> ItemSet {
+        ItemSet(self.0 & other.0)
+    }
+
+    pub fn len(self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    pub fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /** lowest priority first */
+    pub fn priorities(self) -> impl Iterator<Item = Priority> {
+        (1..=52)
+            .map(Priority::from_value)
+            .filter(move |&priority| self.contains(priority))
+    }
+
+    pub fn priority_sum(self) -> u32 {
+        self.priorities().map(Priority::value).sum()
+    }
+}
+
+impl Display for ItemSet {
+    /** the items as letters, lowest priority first */
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.priorities()
+            .try_for_each(|priority| write!(f, "{}", priority.item()))
+    }
+}
+
+impl FromIterator<Priority> for ItemSet {
+    fn from_iter<T: IntoIterator<Item = Priority>>(iter: T) -> Self {
+        iter.into_iter().fold(ItemSet::EMPTY, ItemSet::with)
+    }
+}
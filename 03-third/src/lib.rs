@@ -0,0 +1,206 @@
+pub mod audit;
+pub mod generator;
+pub mod grouping;
+pub mod item_set;
+
+use common::{Answer, ParseError, Solution};
+use grouping::{group_prio_sum, LeftoverLines};
+use item_set::ItemSet;
+use std::{error::Error, fmt::Display, str::FromStr};
+
+/** Priority of an item: `a-z` is 1 to 26, `A-Z` is 27 to 52 */
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Priority(u8);
+
+impl Priority {
+    pub fn value(self) -> u32 {
+        self.0 as u32
+    }
+
+    /** `value` must be from 1 to 52 */
+    pub fn from_value(value: u8) -> Priority {
+        assert!((1..=52).contains(&value), "no item has priority {}", value);
+        Priority(value)
+    }
+
+    /** the letter of the item */
+    pub fn item(self) -> char {
+        match self.0 {
+            1..=26 => (b'a' + self.0 - 1) as char,
+            _ => (b'A' + self.0 - 27) as char,
+        }
+    }
+}
+
+/** An item that is not a letter */
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InvalidItem(pub char);
+
+impl Display for InvalidItem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "`{}` is not an item, items are `a-z` and `A-Z`", self.0)
+    }
+}
+
+impl Error for InvalidItem {}
+
+impl TryFrom<char> for Priority {
+    type Error = InvalidItem;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            'a'..='z' => Ok(Priority(c as u8 - b'a' + 1)),
+            'A'..='Z' => Ok(Priority(c as u8 - b'A' + 27)),
+            _ => Err(InvalidItem(c)),
+        }
+    }
+}
+
+/** Items of a rucksack: letters only, as many in each compartment */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rucksack(Vec<Priority>);
+
+/** Why a line is not a [`Rucksack`] */
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InvalidRucksack {
+    /** the item at this byte offset */
+    Item(usize, InvalidItem),
+    /** no items, or an odd number of them */
+    Length(usize),
+}
+
+impl Display for InvalidRucksack {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InvalidRucksack::Item(_, item) => write!(f, "{}", item),
+            InvalidRucksack::Length(length) => write!(
+                f,
+                "a rucksack holds an even number of items, at least 2, not {}",
+                length
+            ),
+        }
+    }
+}
+
+impl Error for InvalidRucksack {}
+
+impl FromStr for Rucksack {
+    type Err = InvalidRucksack;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let items = s
+            .char_indices()
+            .map(|(i, c)| Priority::try_from(c).map_err(|item| InvalidRucksack::Item(i, item)))
+            .collect::<Result<Vec<_>, _>>()?;
+        if items.is_empty() || items.len() % 2 == 1 {
+            return Err(InvalidRucksack::Length(items.len()));
+        }
+        Ok(Rucksack(items))
+    }
+}
+
+impl Display for Rucksack {
+    /** the items as letters, in the order they were listed */
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0
+            .iter()
+            .try_for_each(|priority| write!(f, "{}", priority.item()))
+    }
+}
+
+impl Rucksack {
+    /** every item in the rucksack */
+    pub fn items(&self) -> ItemSet {
+        self.0.iter().copied().collect()
+    }
+
+    /** the items of the first and of the second compartment */
+    pub fn compartments(&self) -> (ItemSet, ItemSet) {
+        let (first, second) = self.0.split_at(self.0.len() / 2);
+        (
+            first.iter().copied().collect(),
+            second.iter().copied().collect(),
+        )
+    }
+}
+
+/** rucksacks in a group of elves in the puzzle */
+pub const GROUP_SIZE: usize = 3;
+
+pub struct RucksackReorganization;
+
+impl Solution for RucksackReorganization {
+    /**
+     * only the rucksacks are checked: part 1 does not group them, so
+     * rucksacks left after the last full group only leave part 2 unanswered
+     */
+    type Input = Vec<Rucksack>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_rucksacks(input)
+    }
+
+    fn part1(lines: &Self::Input) -> Answer {
+        get_prio_sum(lines).into()
+    }
+
+    fn part2(lines: &Self::Input) -> Answer {
+        match get_group_prio_sum(lines) {
+            Ok(sum) => sum.into(),
+            Err(leftover) => Answer::Unavailable(leftover.to_string()),
+        }
+    }
+}
+
+/** checks every rucksack holds letters only, as many in each compartment */
+pub fn parse_rucksacks(input: &str) -> Result<Vec<Rucksack>, ParseError> {
+    let mut rucksacks = Vec::new();
+    for (i, line) in input.lines().enumerate() {
+        match line.parse() {
+            Ok(rucksack) => rucksacks.push(rucksack),
+            Err(InvalidRucksack::Item(j, InvalidItem(c))) => {
+                return Err(ParseError::at_token(
+                    i + 1,
+                    line,
+                    &line[j..j + c.len_utf8()],
+                    "an item from `a-z` or `A-Z`",
+                ));
+            }
+            Err(InvalidRucksack::Length(_)) => {
+                return Err(ParseError::at_end_of_line(
+                    i + 1,
+                    line,
+                    "an even number of items, half in each compartment",
+                ));
+            }
+        }
+    }
+    Ok(rucksacks)
+}
+
+/** items found in both compartments of `rucksack` */
+pub fn shared_items(rucksack: &Rucksack) -> ItemSet {
+    let (first, second) = rucksack.compartments();
+    first.intersection(second)
+}
+
+/** badge candidates: items found in every rucksack of `group` */
+pub fn group_badges(group: &[Rucksack]) -> ItemSet {
+    group
+        .iter()
+        .map(Rucksack::items)
+        .fold(ItemSet::ALL, ItemSet::intersection)
+}
+
+/** priorities of the items shared by the compartments of each rucksack */
+pub fn get_prio_sum(lines: &[Rucksack]) -> u32 {
+    lines
+        .iter()
+        .map(|line| shared_items(line).priority_sum())
+        .sum()
+}
+
+/** priorities of the badges of each group of three rucksacks */
+pub fn get_group_prio_sum(lines: &[Rucksack]) -> Result<u32, LeftoverLines> {
+    group_prio_sum(lines, GROUP_SIZE)
+}
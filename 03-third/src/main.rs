@@ -1,60 +1,151 @@
-use std::{
-    fs::File,
-    io::{BufRead, BufReader},
+use clap::{builder::RangedU64ValueParser, Parser, ValueEnum};
+use common::InputSource;
+use std::{fs, process};
+use third::{
+    audit::Audit,
+    generator::{generate, GeneratorConfig},
+    get_prio_sum,
+    grouping::{find_partition, group_prio_sum, single_badge_windows, Window},
+    parse_rucksacks, GROUP_SIZE,
 };
 
-fn main() {
-    let lines = get_lines("input");
-    println!("rucksack priorities sum : {}", get_prio_sum(&lines));
-    println!(
-        "rucksack group priorities sum : {}",
-        get_group_prio_sum(&lines)
-    );
-}
-
-fn get_lines(filename: &str) -> Vec<String> {
-    BufReader::new(File::open(filename).expect("file not found"))
-        .lines()
-        .map(|l| l.expect("error line read"))
-        .collect()
+#[derive(Clone, Copy, ValueEnum)]
+enum Grouping {
+    /// Consecutive groups of the group size, as in the puzzle
+    Chunks,
+    /// Every run of consecutive rucksacks of the group size sharing a single badge
+    Windows,
+    /// A split into consecutive groups of 2 up to the group size, each with a single badge
+    Partition,
 }
 
-fn get_common_char(a: &str, b: &str) -> char {
-    b.chars().filter(|c| a.contains(*c)).last().unwrap()
+#[derive(Clone, Copy, ValueEnum)]
+enum AuditFormat {
+    Table,
+    Json,
 }
 
-fn get_group_common_char(group: &[String]) -> char {
-    group[0]
-        .chars()
-        .filter(|c| group[1].contains(*c) && group[2].contains(*c))
-        .last()
-        .unwrap()
+#[derive(Parser)]
+#[command(about = "Day 3: priorities of the misplaced items and of the group badges")]
+struct Cli {
+    /// Puzzle input file, `-` for stdin
+    #[arg(default_value = "input")]
+    input: String,
+    /// Rucksacks in a group of elves
+    #[arg(long, default_value_t = GROUP_SIZE)]
+    #[arg(value_parser = RangedU64ValueParser::<usize>::new().range(1..))]
+    group_size: usize,
+    /// How rucksacks are grouped to find badges
+    #[arg(long, value_enum, default_value_t = Grouping::Chunks)]
+    grouping: Grouping,
+    /// Print the items shared by each rucksack and group instead, flagging
+    /// the ones without exactly one
+    #[arg(long, value_enum, conflicts_with = "grouping")]
+    audit: Option<AuditFormat>,
+    /// Print a valid input of `--rucksacks` rucksacks in groups of `--group-size` instead
+    #[arg(long, conflicts_with_all = ["grouping", "audit"])]
+    generate: bool,
+    /// Seed of the generated input
+    #[arg(long, default_value_t = 0, requires = "generate")]
+    seed: u64,
+    /// Rucksacks in the generated input
+    #[arg(long, default_value_t = 300, requires = "generate")]
+    rucksacks: usize,
+    /// Items in each generated rucksack
+    #[arg(long, default_value_t = 48, requires = "generate")]
+    line_length: usize,
+    /// Where to write the answers of the generated input, as an `answers.toml`,
+    /// instead of stderr
+    #[arg(long, requires = "generate")]
+    answers: Option<String>,
 }
 
-fn get_char_priority(c: char) -> u32 {
-    let value = c as u32 - 48;
-    if value >= 48 {
-        value - 48
-    } else {
-        value + 10
+/** prints the generated input, and writes its answers */
+fn generate_input(cli: &Cli) -> Result<(), String> {
+    let generated = generate(&GeneratorConfig {
+        seed: cli.seed,
+        rucksacks: cli.rucksacks,
+        line_length: cli.line_length,
+        group_size: cli.group_size,
+    })
+    .map_err(|error| error.to_string())?;
+    println!("{}", generated.input);
+    match &cli.answers {
+        Some(path) => fs::write(path, generated.answers_toml())
+            .map_err(|error| format!("cannot write {}: {}", path, error)),
+        None => {
+            eprint!("{}", generated.answers_toml());
+            Ok(())
+        }
     }
 }
 
-fn get_prio_sum(lines: &Vec<String>) -> u32 {
-    let mut sum = 0;
-    for line in lines {
-        let (first, second) = line.split_at(line.len() / 2);
-        let c = get_common_char(first, second);
-        sum += get_char_priority(c)
+fn print_groups(groups: &[Window]) {
+    for group in groups {
+        println!(
+            "lines {}-{} : {}",
+            group.lines.start + 1,
+            group.lines.end,
+            group.badges
+        );
     }
-    sum
 }
 
-fn get_group_prio_sum(lines: &Vec<String>) -> u32 {
-    let mut sum = 0;
-    for group in lines.chunks_exact(3) {
-        let c = get_group_common_char(group);
-        sum += get_char_priority(c)
+fn main() {
+    let cli = Cli::parse();
+    if cli.generate {
+        if let Err(error) = generate_input(&cli) {
+            eprintln!("{}", error);
+            process::exit(1);
+        }
+        return;
+    }
+    let input = match InputSource::from_arg(&cli.input).read() {
+        Ok(input) => input,
+        Err(error) => {
+            eprintln!("{}", error);
+            process::exit(1);
+        }
+    };
+    let lines = match parse_rucksacks(&input) {
+        Ok(lines) => lines,
+        Err(error) => {
+            eprintln!("{}", error.render(&cli.input, &input));
+            process::exit(1);
+        }
+    };
+    if let Some(format) = cli.audit {
+        let audit = Audit::new(&lines, cli.group_size);
+        match format {
+            AuditFormat::Table => print!("{}", audit.to_table()),
+            AuditFormat::Json => println!("{}", audit.to_json()),
+        }
+        return;
+    }
+    println!("rucksack priorities sum : {}", get_prio_sum(&lines));
+    match cli.grouping {
+        Grouping::Chunks => match group_prio_sum(&lines, cli.group_size) {
+            Ok(sum) => println!("rucksack group priorities sum : {}", sum),
+            Err(leftover) => {
+                eprintln!("{}: {}", cli.input, leftover);
+                process::exit(1);
+            }
+        },
+        Grouping::Windows => print_groups(&single_badge_windows(&lines, cli.group_size)),
+        Grouping::Partition => match find_partition(&lines, 2.min(cli.group_size)..=cli.group_size)
+        {
+            Some(groups) => {
+                print_groups(&groups);
+                let sum: u32 = groups.iter().map(|g| g.badges.priority_sum()).sum();
+                println!("rucksack group priorities sum : {}", sum);
+            }
+            None => {
+                eprintln!(
+                    "{}: no split into groups of 2 to {} rucksacks with a single badge each",
+                    cli.input, cli.group_size
+                );
+                process::exit(1);
+            }
+        },
     }
-    sum
 }
@@ -0,0 +1,39 @@
+use common::testing;
+use third::RucksackReorganization;
+
+#[test]
+fn example() {
+    testing::assert_answers::<RucksackReorganization>(include_str!("../example"), 157, 70);
+}
+
+#[test]
+fn puzzle_input() {
+    testing::assert_answers_file::<RucksackReorganization>(env!("CARGO_MANIFEST_DIR"));
+}
+
+#[test]
+fn invalid_rucksacks() {
+    use common::Solution;
+    let error = RucksackReorganization::parse("abAB\nab1B").unwrap_err();
+    assert_eq!(
+        (error.line, error.column, error.found.as_str()),
+        (2, 3, "1")
+    );
+    let error = RucksackReorganization::parse("abAB\nabcAB").unwrap_err();
+    assert_eq!((error.line, error.column), (2, 6));
+    assert!(RucksackReorganization::parse("abAB\n\nabAB").is_err());
+}
+
+#[test]
+fn leftover_rucksacks_only_block_part_2() {
+    use common::{Answer, Solution};
+    let lines = RucksackReorganization::parse("aa\nbb\ncc\ndd").unwrap();
+    assert_eq!(
+        RucksackReorganization::part1(&lines),
+        (1 + 2 + 3 + 4).into()
+    );
+    assert_eq!(
+        RucksackReorganization::part2(&lines),
+        Answer::Unavailable("1 rucksack(s) from line 4 do not make a full group of 3".to_string())
+    );
+}
@@ -0,0 +1,29 @@
+use third::{
+    audit::{Audit, Status},
+    parse_rucksacks,
+};
+
+#[test]
+fn example_is_clean() {
+    let lines = parse_rucksacks(include_str!("../example")).unwrap();
+    let audit = Audit::new(&lines, 3);
+    assert_eq!(audit.flagged(), (0, 0));
+    assert_eq!(audit.groups[1].lines, 4..7);
+    assert_eq!(audit.groups[1].badges.to_string(), "Z");
+}
+
+#[test]
+fn flags_misplaced_items_and_ambiguous_badges() {
+    let lines = parse_rucksacks("abcabc\nabcd\nazbz").unwrap();
+    let audit = Audit::new(&lines, 2);
+    let statuses: Vec<Status> = audit.rucksacks.iter().map(|r| r.status).collect();
+    assert_eq!(statuses, vec![Status::Multiple, Status::None, Status::Ok]);
+    assert_eq!(audit.rucksacks[0].shared.to_string(), "abc");
+    assert_eq!(audit.groups[0].status, Status::Multiple);
+    assert_eq!(audit.groups[1].status, Status::Incomplete);
+
+    let json = audit.to_json();
+    assert_eq!(json["flagged_rucksacks"], 2);
+    assert_eq!(json["groups"][0]["badges"], "abc");
+    assert_eq!(json["groups"][1]["first_line"], 3);
+}
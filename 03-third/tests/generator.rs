@@ -0,0 +1,80 @@
+use common::{testing, Answer};
+use third::{
+    audit::Audit,
+    generator::{generate, GeneratorConfig},
+    get_prio_sum,
+    grouping::group_prio_sum,
+    parse_rucksacks, RucksackReorganization,
+};
+
+#[test]
+fn answers_match_the_solvers() {
+    for seed in 0..64 {
+        let config = GeneratorConfig {
+            seed,
+            group_size: 1 + seed as usize % 5,
+            rucksacks: (1 + seed as usize % 7) * (1 + seed as usize % 5),
+            line_length: 2 * (1 + seed as usize % 40),
+        };
+        let generated = generate(&config).unwrap();
+        let lines = parse_rucksacks(&generated.input).unwrap();
+        assert_eq!(lines.len(), config.rucksacks, "{:?}", config);
+        assert!(lines
+            .iter()
+            .all(|l| l.to_string().len() == config.line_length));
+        assert_eq!(get_prio_sum(&lines), generated.part1, "{:?}", config);
+        assert_eq!(
+            group_prio_sum(&lines, config.group_size),
+            Ok(generated.part2),
+            "{:?}",
+            config
+        );
+        assert_eq!(Audit::new(&lines, config.group_size).flagged(), (0, 0));
+    }
+}
+
+#[test]
+fn large_puzzle_sized_input() {
+    let generated = generate(&GeneratorConfig {
+        seed: 2022,
+        rucksacks: 3_000,
+        line_length: 1_000,
+        group_size: 3,
+    })
+    .unwrap();
+    testing::assert_answers::<RucksackReorganization>(
+        &generated.input,
+        Answer::from(generated.part1),
+        Answer::from(generated.part2),
+    );
+}
+
+#[test]
+fn same_seed_same_input() {
+    let config = GeneratorConfig {
+        seed: 7,
+        rucksacks: 30,
+        line_length: 24,
+        group_size: 3,
+    };
+    assert_eq!(generate(&config), generate(&config));
+    let other = GeneratorConfig { seed: 8, ..config };
+    assert_ne!(generate(&config), generate(&other));
+}
+
+#[test]
+fn invalid_configs() {
+    let config = GeneratorConfig {
+        seed: 0,
+        rucksacks: 4,
+        line_length: 24,
+        group_size: 3,
+    };
+    assert!(generate(&config).is_err());
+    assert!(generate(&GeneratorConfig {
+        rucksacks: 3,
+        line_length: 7,
+        ..config
+    })
+    .is_err());
+}
@@ -0,0 +1,42 @@
+use third::{
+    grouping::{find_partition, full_groups, group_prio_sum, single_badge_windows, LeftoverLines},
+    parse_rucksacks, Rucksack,
+};
+
+fn example() -> Vec<Rucksack> {
+    parse_rucksacks(include_str!("../example")).unwrap()
+}
+
+#[test]
+fn group_sizes() {
+    let lines = example();
+    assert_eq!(group_prio_sum(&lines, 3), Ok(70));
+    assert_eq!(full_groups(&lines, 2).unwrap().len(), 3);
+    assert_eq!(
+        group_prio_sum(&lines, 4),
+        Err(LeftoverLines {
+            group_size: 4,
+            first_line: 5,
+            count: 2
+        })
+    );
+}
+
+#[test]
+fn windows() {
+    let windows = single_badge_windows(&example(), 3);
+    let starts: Vec<usize> = windows.iter().map(|w| w.lines.start).collect();
+    assert_eq!(starts, vec![0, 1, 2, 3]);
+    assert_eq!(windows[0].badges.to_string(), "r");
+}
+
+#[test]
+fn partitions() {
+    let groups = find_partition(&example(), 3..=3).unwrap();
+    let badges: Vec<String> = groups.iter().map(|g| g.badges.to_string()).collect();
+    assert_eq!(badges, vec!["r", "Z"]);
+
+    let lines = parse_rucksacks("abcd\naefg\nhijk").unwrap();
+    assert_eq!(find_partition(&lines, 2..=2), None);
+    assert_eq!(find_partition(&lines[..2], 2..=3).unwrap()[0].lines, 0..2);
+}
@@ -0,0 +1,38 @@
+use third::{
+    group_badges, item_set::ItemSet, shared_items, InvalidItem, InvalidRucksack, Rucksack,
+};
+
+fn rucksack(items: &str) -> Rucksack {
+    items.parse().unwrap()
+}
+
+#[test]
+fn item_sets() {
+    assert_eq!(
+        shared_items(&rucksack("vJrwpWtwJgWrhcsFMMfFFhFp")).to_string(),
+        "p"
+    );
+    assert_eq!(shared_items(&rucksack("abcCab")).to_string(), "ab");
+    assert!(shared_items(&rucksack("abcd")).is_empty());
+
+    let all: String = ItemSet::ALL.to_string();
+    assert_eq!(all.len(), 52);
+    assert!(all.starts_with("abc") && all.ends_with("XYZ"));
+
+    let group = [
+        rucksack("vJrwpWtwJgWrhcsFMMfFFhFp"),
+        rucksack("jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL"),
+    ];
+    assert_eq!(group_badges(&group).to_string(), "frsFM");
+}
+
+#[test]
+fn invalid_rucksacks() {
+    assert_eq!(
+        "ab1B".parse::<Rucksack>(),
+        Err(InvalidRucksack::Item(2, InvalidItem('1')))
+    );
+    assert_eq!("abc".parse::<Rucksack>(), Err(InvalidRucksack::Length(3)));
+    assert_eq!("".parse::<Rucksack>(), Err(InvalidRucksack::Length(0)));
+    assert_eq!(rucksack("abAB").to_string(), "abAB");
+}
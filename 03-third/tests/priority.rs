@@ -0,0 +1,13 @@
+use third::{InvalidItem, Priority};
+
+#[test]
+fn priorities() {
+    let value = |c| Priority::try_from(c).map(Priority::value);
+    assert_eq!(value('a'), Ok(1));
+    assert_eq!(value('z'), Ok(26));
+    assert_eq!(value('A'), Ok(27));
+    assert_eq!(value('Z'), Ok(52));
+    for c in ['0', '[', '`', '{', '\t', '\0', 'é'] {
+        assert_eq!(value(c), Err(InvalidItem(c)));
+    }
+}
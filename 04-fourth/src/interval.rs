@@ -0,0 +1,100 @@
+use std::{error::Error, fmt::Display, str::FromStr};
+
+/** Inclusive range of section ids, `start` never after `end` */
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Interval {
+    start: u32,
+    end: u32,
+}
+
+/** Why a text or a pair of bounds is not an [`Interval`] */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InvalidInterval {
+    /** not two section ids separated by `-` */
+    Format(String),
+    Reversed {
+        start: u32,
+        end: u32,
+    },
+}
+
+impl Display for InvalidInterval {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InvalidInterval::Format(text) => {
+                write!(f, "`{}` is not a range of sections like `2-4`", text)
+            }
+            InvalidInterval::Reversed { start, end } => {
+                write!(f, "the range {}-{} starts after its end", start, end)
+            }
+        }
+    }
+}
+
+impl Error for InvalidInterval {}
+
+// an inclusive interval always holds its start, so it is never empty
+#[allow(clippy::len_without_is_empty)]
+impl Interval {
+    pub fn new(start: u32, end: u32) -> Result<Interval, InvalidInterval> {
+        if start > end {
+            return Err(InvalidInterval::Reversed { start, end });
+        }
+        Ok(Interval { start, end })
+    }
+
+    pub fn start(self) -> u32 {
+        self.start
+    }
+
+    pub fn end(self) -> u32 {
+        self.end
+    }
+
+    /** number of sections, `u64` as `0-4294967295` holds one more than `u32::MAX` */
+    pub fn len(self) -> u64 {
+        (self.end - self.start) as u64 + 1
+    }
+
+    pub fn contains_section(self, section: u32) -> bool {
+        self.start <= section && section <= self.end
+    }
+
+    /** every section of `other` is in `self` */
+    pub fn contains(&self, other: &Interval) -> bool {
+        self.start <= other.start && other.end <= self.end
+    }
+
+    /** at least one section is in both */
+    pub fn overlaps(&self, other: &Interval) -> bool {
+        self.start <= other.end && other.start <= self.end
+    }
+
+    /** the sections in both, `None` when they do not overlap */
+    pub fn intersection(&self, other: &Interval) -> Option<Interval> {
+        Interval::new(self.start.max(other.start), self.end.min(other.end)).ok()
+    }
+}
+
+impl Display for Interval {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}-{}", self.start, self.end)
+    }
+}
+
+impl FromStr for Interval {
+    type Err = InvalidInterval;
+
+    /** `a-b` with `a` and `b` section ids, `a` not above `b` */
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let format_error = || InvalidInterval::Format(s.to_string());
+        let (start, end) = s.split_once('-').ok_or_else(format_error)?;
+        let section = |id: &str| {
+            if id.is_empty() || !id.bytes().all(|b| b.is_ascii_digit()) {
+                return Err(format_error());
+            }
+            id.parse::<u32>().map_err(|_| format_error())
+        };
+        Interval::new(section(start)?, section(end)?)
+    }
+}
@@ -0,0 +1,56 @@
+pub mod interval;
+
+use common::{Answer, ParseError, Solution};
+use interval::{Interval, InvalidInterval};
+
+pub struct CampCleanup;
+
+impl Solution for CampCleanup {
+    /** the sections assigned to each elf of a pair */
+    type Input = Vec<(Interval, Interval)>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut pairs = Vec::new();
+        for (i, pair) in input.lines().enumerate() {
+            let Some((first, second)) = pair.split_once(',') else {
+                return Err(ParseError::at_token(
+                    i + 1,
+                    pair,
+                    pair,
+                    "a pair like `2-4,6-8`",
+                ));
+            };
+            let parse_interval = |sections: &str| {
+                sections.parse::<Interval>().map_err(|error| {
+                    let expected = match error {
+                        InvalidInterval::Format(_) => "a range of sections like `2-4`",
+                        InvalidInterval::Reversed { .. } => {
+                            "a range that does not start after its end"
+                        }
+                    };
+                    ParseError::at_token(i + 1, pair, sections, expected)
+                })
+            };
+            pairs.push((parse_interval(first)?, parse_interval(second)?));
+        }
+        Ok(pairs)
+    }
+
+    fn part1(pairs: &Self::Input) -> Answer {
+        count_overlaps(pairs).0.into()
+    }
+
+    fn part2(pairs: &Self::Input) -> Answer {
+        count_overlaps(pairs).1.into()
+    }
+}
+
+/** `(pairs where one range contains the other, pairs whose ranges overlap)` */
+pub fn count_overlaps(pairs: &[(Interval, Interval)]) -> (usize, usize) {
+    let full = pairs
+        .iter()
+        .filter(|(a, b)| a.contains(b) || b.contains(a))
+        .count();
+    let partial = pairs.iter().filter(|(a, b)| a.overlaps(b)).count();
+    (full, partial)
+}
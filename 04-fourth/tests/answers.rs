@@ -0,0 +1,26 @@
+use common::{testing, Solution};
+use fourth::CampCleanup;
+
+#[test]
+fn example() {
+    testing::assert_answers::<CampCleanup>(include_str!("../example"), 2, 4);
+}
+
+#[test]
+fn touching_and_nested_sections() {
+    testing::assert_answers::<CampCleanup>("1-5,2-3\n2-3,1-5\n1-3,3-5\n1-2,3-4\n3-3,3-3", 3, 4);
+}
+
+#[test]
+fn puzzle_input() {
+    testing::assert_answers_file::<CampCleanup>(env!("CARGO_MANIFEST_DIR"));
+}
+
+#[test]
+fn invalid_ranges() {
+    let error = CampCleanup::parse("2-4,6-8\n2-4,8-6").unwrap_err();
+    assert_eq!((error.line, error.column), (2, 5));
+    let error = CampCleanup::parse("2-4;6-8").unwrap_err();
+    assert_eq!((error.line, error.column), (1, 1));
+    assert!(CampCleanup::parse("2-4,6-x").is_err());
+}
@@ -0,0 +1,79 @@
+use fourth::interval::{Interval, InvalidInterval};
+
+/** every interval within sections `0..=MAX` */
+const MAX: u32 = 7;
+
+fn all_intervals() -> Vec<Interval> {
+    (0..=MAX)
+        .flat_map(|start| (start..=MAX).map(move |end| Interval::new(start, end).unwrap()))
+        .collect()
+}
+
+fn sections(interval: Interval) -> Vec<u32> {
+    (0..=MAX)
+        .filter(|&s| interval.contains_section(s))
+        .collect()
+}
+
+#[test]
+fn predicates_match_sections() {
+    for a in all_intervals() {
+        let in_a = sections(a);
+        assert_eq!(in_a.len() as u64, a.len(), "{}", a);
+        for b in all_intervals() {
+            let in_b = sections(b);
+            let common: Vec<u32> = in_a.iter().copied().filter(|s| in_b.contains(s)).collect();
+            assert_eq!(
+                a.contains(&b),
+                in_b.iter().all(|s| in_a.contains(s)),
+                "{} contains {}",
+                a,
+                b
+            );
+            assert_eq!(a.overlaps(&b), !common.is_empty(), "{} overlaps {}", a, b);
+            assert_eq!(
+                a.intersection(&b).map(sections),
+                (!common.is_empty()).then_some(common),
+                "{} & {}",
+                a,
+                b
+            );
+            assert_eq!(a.overlaps(&b), b.overlaps(&a));
+            assert_eq!(a.intersection(&b), b.intersection(&a));
+        }
+    }
+}
+
+#[test]
+fn parse_and_display() {
+    for interval in all_intervals() {
+        assert_eq!(interval.to_string().parse(), Ok(interval));
+    }
+    assert_eq!("3-3".parse(), Ok(Interval::new(3, 3).unwrap()));
+    assert_eq!(
+        "0-4294967295".parse::<Interval>().map(Interval::len),
+        Ok(1 << 32)
+    );
+    assert_eq!(
+        "5-2".parse::<Interval>(),
+        Err(InvalidInterval::Reversed { start: 5, end: 2 })
+    );
+    for text in [
+        "",
+        "3",
+        "-3",
+        "3-",
+        "a-4",
+        "+1-4",
+        "1-4-5",
+        " 1-4",
+        "1-4294967296",
+    ] {
+        assert_eq!(
+            text.parse::<Interval>(),
+            Err(InvalidInterval::Format(text.to_string())),
+            "{:?}",
+            text
+        );
+    }
+}
@@ -0,0 +1,166 @@
+use common::{parse_token, Answer, ParseError, Solution};
+use regex::Regex;
+
+#[derive(Debug, Clone)]
+pub struct ElfCrate {
+    pub label: char,
+}
+
+pub type ElfCrateStack = Vec<ElfCrate>;
+
+pub type ElfCrates = [ElfCrateStack; 9];
+pub trait CrateOperations {
+    fn move_crate(&mut self, from_stack_index: usize, to_stack_index: usize);
+    fn move_crates(&mut self, amount: usize, from_stack_index: usize, to_stack_index: usize);
+
+}
+
+impl CrateOperations for ElfCrates {
+    fn move_crate(&mut self, from_stack_index: usize, to_stack_index: usize) {
+        let popped = self[from_stack_index].pop().unwrap();
+        self[to_stack_index].push(popped);
+    }
+
+    fn move_crates(&mut self, amount: usize, from_stack_index: usize, to_stack_index: usize) {
+        let from_stack = &mut self[from_stack_index];
+        let drained:Vec<ElfCrate> = from_stack.drain(from_stack.len()-amount..).collect();
+        self[to_stack_index].extend(drained);
+    }
+}
+pub struct SupplyStacks;
+
+impl Solution for SupplyStacks {
+    /** starting crate stacks and `(amount, from, to)` moves */
+    type Input = (ElfCrates, Vec<(usize, usize, usize)>);
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let lines: Vec<String> = input.lines().map(|l| l.to_string()).collect();
+
+        let Some(crates_moves_separation) = lines.iter().position(|line| line.is_empty()) else {
+            return Err(ParseError::new(
+                lines.len() + 1,
+                1,
+                "a blank line between crates and moves",
+                "",
+            ));
+        };
+        let crates_and_moves = lines.split_at(crates_moves_separation);
+
+        let (crates, moves) = crates_and_moves;
+        let elf_crates = parse_crates(crates)?;
+        let moves = parse_moves(moves, crates_moves_separation + 1, &elf_crates)?;
+        Ok((elf_crates, moves))
+    }
+
+    fn part1((elf_crates, moves): &Self::Input) -> Answer {
+        let mut elf_crates = elf_crates.clone();
+        for crate_move in moves {
+            for _ in 0..crate_move.0 {
+                elf_crates.move_crate(crate_move.1 - 1, crate_move.2 - 1)
+            }
+        }
+        top_labels(elf_crates).into()
+    }
+
+    fn part2((elf_crates, moves): &Self::Input) -> Answer {
+        let mut elf_crates = elf_crates.clone();
+        for crate_move in moves {
+            elf_crates.move_crates(crate_move.0, crate_move.1 - 1, crate_move.2 - 1)
+        }
+        top_labels(elf_crates).into()
+    }
+}
+
+/** label of the top crate of each stack, empty stacks are skipped */
+pub fn top_labels(elf_crates: ElfCrates) -> String {
+    elf_crates
+        .iter()
+        .filter_map(|ec| ec.last())
+        .map(|c| c.label.to_string())
+        .collect::<Vec<String>>()
+        .join("")
+}
+
+/** in `[1; 9]` */
+pub fn get_crate_index(index: usize) -> usize {
+    (index as f32 / 4.0).ceil() as usize
+}
+
+pub fn parse_crates(crates: &[String]) -> Result<ElfCrates, ParseError> {
+    let crate_name_search: Regex = Regex::new(r"^[^\s\[\]\d]$").unwrap();
+    let mut elf_crates: [ElfCrateStack; 9] = Default::default();
+    for (i, line) in crates.iter().enumerate().rev() {
+        for unit in line
+            .char_indices()
+            .filter(|(_, c)| crate_name_search.is_match(&c.to_string()))
+        {
+            let x: usize = get_crate_index(unit.0);
+            if unit.0 % 4 != 1 || x > elf_crates.len() {
+                let label = &line[unit.0..unit.0 + unit.1.len_utf8()];
+                return Err(ParseError::at_token(
+                    i + 1,
+                    line,
+                    label,
+                    "a crate like `[A]` in one of the 9 stacks",
+                ));
+            }
+
+            elf_crates[x - 1].push(ElfCrate { label: unit.1 });
+        }
+    }
+    Ok(elf_crates)
+}
+
+/**
+ * `moves` starts with the blank line separating them from the crates,
+ * found at line number `first_line`; the moves are replayed on the heights
+ * of `elf_crates` so that none takes more crates than its stack holds
+ */
+pub fn parse_moves(
+    moves: &[String],
+    first_line: usize,
+    elf_crates: &ElfCrates,
+) -> Result<Vec<(usize, usize, usize)>, ParseError> {
+    let re: Regex = Regex::new(r"\d+").unwrap();
+    let mut heights = elf_crates.each_ref().map(Vec::len);
+    let mut crate_moves: Vec<(usize, usize, usize)> = Default::default();
+    for (i, line) in moves.iter().enumerate().skip(1) {
+        let line_number = first_line + i;
+        let numbers: Vec<&str> = re.find_iter(line).map(|digits| digits.as_str()).collect();
+        let mut find = [0; 3];
+        for (k, expected) in [
+            "an amount of crates",
+            "a stack number from 1 to 9",
+            "a stack number from 1 to 9",
+        ]
+        .iter()
+        .enumerate()
+        {
+            let Some(digits) = numbers.get(k) else {
+                return Err(ParseError::at_end_of_line(line_number, line, expected));
+            };
+            find[k] = parse_token(line_number, line, digits, expected)?;
+            if k > 0 && !(1..=9).contains(&find[k]) {
+                return Err(ParseError::at_token(line_number, line, digits, expected));
+            }
+        }
+        let [amount, from, to] = find;
+        if amount > heights[from - 1] {
+            let expected = format!(
+                "an amount of at most {}, the crates on stack {}",
+                heights[from - 1],
+                from
+            );
+            return Err(ParseError::at_token(
+                line_number,
+                line,
+                numbers[0],
+                &expected,
+            ));
+        }
+        heights[from - 1] -= amount;
+        heights[to - 1] += amount;
+        crate_moves.push((amount, from, to));
+    }
+    Ok(crate_moves)
+}
@@ -0,0 +1,22 @@
+use common::{testing, Solution};
+use fifth::SupplyStacks;
+
+#[test]
+fn example() {
+    testing::assert_answers::<SupplyStacks>(include_str!("../example"), "CMZ", "MCD");
+}
+
+#[test]
+fn puzzle_input() {
+    testing::assert_answers_file::<SupplyStacks>(env!("CARGO_MANIFEST_DIR"));
+}
+
+#[test]
+fn moves_from_too_small_stacks() {
+    let error = SupplyStacks::parse("[A]\n 1 \n\nmove 3 from 1 to 2").unwrap_err();
+    assert_eq!((error.line, error.column), (4, 6));
+    let error =
+        SupplyStacks::parse("[A]\n 1 \n\nmove 1 from 1 to 2\nmove 1 from 1 to 3").unwrap_err();
+    assert_eq!((error.line, error.column), (5, 6));
+    assert!(SupplyStacks::parse("[A]\n 1 \n\nmove 1 from 1 to 2\nmove 1 from 2 to 1").is_ok());
+}
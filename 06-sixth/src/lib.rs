@@ -0,0 +1,64 @@
+use common::{Answer, ParseError, Solution};
+use std::collections::HashSet;
+
+pub struct TuningTrouble;
+
+impl Solution for TuningTrouble {
+    type Input = Vec<char>;
+
+    /** also checks the signal holds both markers */
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let line = input.trim_end();
+        let signal: Vec<char> = line.chars().collect();
+        for (window_size, marker) in [(PACKET_MARKER, "packet"), (MESSAGE_MARKER, "message")] {
+            if find_marker(&signal, window_size).is_none() {
+                let expected = format!(
+                    "a start-of-{} marker, {} different characters in a row",
+                    marker, window_size
+                );
+                return Err(ParseError::at_end_of_line(1, line, &expected));
+            }
+        }
+        Ok(signal)
+    }
+
+    fn part1(signal: &Self::Input) -> Answer {
+        find_marker(signal, PACKET_MARKER)
+            .expect("parsing checks the signal has markers")
+            .into()
+    }
+
+    fn part2(signal: &Self::Input) -> Answer {
+        find_marker(signal, MESSAGE_MARKER)
+            .expect("parsing checks the signal has markers")
+            .into()
+    }
+}
+
+/** distinct chars in a start-of-packet marker */
+pub const PACKET_MARKER: usize = 4;
+/** distinct chars in a start-of-message marker */
+pub const MESSAGE_MARKER: usize = 14;
+
+/**
+ * position right after the first `window_size` distinct chars,
+ * `None` when the signal has no such chars
+ */
+pub fn find_marker(signal: &[char], window_size: usize) -> Option<usize> {
+    let char_set = signal
+    .windows(window_size)
+    .map(|c| c.iter().collect::<String>())
+    .enumerate()
+    .filter_map(|(i, value)| all_unique_chars(i,value))
+    .collect::<Vec<(usize, String)>>();
+    char_set.first().map(|(i, _)| i + window_size)
+}
+
+pub fn all_unique_chars(index: usize, value: String) -> Option<(usize, String)> {
+    let set: HashSet<char> = HashSet::from_iter(value.chars().collect::<Vec<char>>());
+    if set.len() == value.len() {
+        Some((index, value))
+    } else {
+        None
+    }
+}
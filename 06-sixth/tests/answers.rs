@@ -0,0 +1,22 @@
+use common::{testing, Solution};
+use sixth::TuningTrouble;
+
+#[test]
+fn example() {
+    testing::assert_answers::<TuningTrouble>(include_str!("../example"), 7, 19);
+}
+
+#[test]
+fn puzzle_input() {
+    testing::assert_answers_file::<TuningTrouble>(env!("CARGO_MANIFEST_DIR"));
+}
+
+#[test]
+fn signals_without_markers() {
+    for signal in ["", "abc", "abcd", "abcdefghijklmabcdefghijklm"] {
+        let error = TuningTrouble::parse(signal).unwrap_err();
+        assert_eq!((error.line, error.column), (1, signal.len() + 1), "{:?}", signal);
+    }
+    assert_eq!(sixth::find_marker(&['a', 'b', 'c'], 4), None);
+    testing::assert_answers::<TuningTrouble>("abcdefghijklmn", 4, 14);
+}
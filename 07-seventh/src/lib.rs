@@ -0,0 +1,239 @@
+use common::{parse_token, Answer, ParseError, Solution};
+use log::{debug, log_enabled, Level};
+use std::{cell::Cell, rc::Rc};
+
+/** a file, or a directory when `size` is negative */
+#[derive(Debug)]
+pub struct ElfFile {
+    pub name: String,
+    pub size: i64,
+    pub parent: Option<Rc<ElfFile>>,
+    pub total_size: Cell<i64>,
+    pub depth: usize,
+}
+
+impl ElfFile {
+    pub fn is_dir(&self) -> bool {
+        self.size < 0
+    }
+
+    pub fn is_root(&self) -> bool {
+        self.name == "/"
+    }
+
+    pub fn get_content(&self, tree: &Vec<Rc<ElfFile>>) -> Option<Vec<Rc<ElfFile>>> {
+        if !self.is_dir() {
+            return None;
+        }
+        let mut files: Vec<Rc<ElfFile>> = Vec::new();
+        for file in tree {
+            if !file.is_root() && self.name == file.parent.clone().unwrap().name {
+                files.push(file.clone())
+            }
+        }
+        if !files.is_empty() {
+            return Some(files);
+        }
+        None
+    }
+}
+
+pub struct NoSpaceLeftOnDevice;
+
+impl Solution for NoSpaceLeftOnDevice {
+    type Input = Vec<Rc<ElfFile>>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let history: Vec<String> = input.lines().map(|l| l.to_string()).collect();
+        build_file_tree(&history)
+    }
+
+    fn part1(file_tree: &Self::Input) -> Answer {
+        if log_enabled!(Level::Debug) {
+            print_tree(file_tree);
+        }
+
+        let sum = file_tree
+            .iter()
+            .filter(|f| f.total_size.get() <= 100000 && f.total_size.get() > 0)
+            .map(|f| f.total_size.get())
+            .sum::<i64>();
+
+        sum.into()
+    }
+
+    fn part2(file_tree: &Self::Input) -> Answer {
+        let space_used = file_tree
+            .iter()
+            .find(|f| f.is_root())
+            .unwrap()
+            .total_size
+            .get();
+
+        const SPACE_TOTAL: i64 = 70_000_000;
+        const SPACE_NEEDED: i64 = 30_000_000;
+        debug!("free space {}", SPACE_TOTAL - space_used);
+        let space_to_free = SPACE_NEEDED - (SPACE_TOTAL - space_used);
+        debug!("space to free : {}", space_to_free);
+
+        let to_remove_size = file_tree
+            .iter()
+            .filter(|f| f.total_size.get() > space_to_free)
+            .map(|f| f.total_size.get())
+            .min()
+            .unwrap();
+
+        to_remove_size.into()
+    }
+}
+
+/** logs the tree at debug level */
+pub fn print_tree(file_tree: &Vec<Rc<ElfFile>>) {
+    for file in file_tree.iter().filter(|f| f.is_dir()) {
+        if let Some(content) = file.get_content(file_tree) {
+            print_content(file, &content);
+        }
+    }
+}
+
+pub fn print_content(file: &Rc<ElfFile>, content: &Vec<Rc<ElfFile>>) {
+    debug!(
+        "{} ┍{} ({})",
+        " ".repeat(file.depth) + "",
+        file.name,
+        file.total_size.get(),
+    );
+    let mut subfolders = content.iter().filter(|f| !f.is_dir()).peekable();
+
+    while let Some(content_file) = subfolders.next() {
+        if let Some(sub) = content_file.get_content(content) {
+            print_content(content_file, &sub);
+        }
+        debug!(
+            "{}┄┄{} ({})",
+            if subfolders.peek().is_none() {
+                " ".repeat(file.depth) + " └"
+            } else {
+                " ".repeat(file.depth) + " ├"
+            },
+            content_file.name,
+            content_file.size
+        );
+    }
+}
+
+/** replays the terminal history, root first; directories get their `total_size` */
+pub fn build_file_tree(history: &[String]) -> Result<Vec<Rc<ElfFile>>, ParseError> {
+    match history.first() {
+        Some(first) if first == "$ cd /" => (),
+        Some(first) => return Err(ParseError::at_token(1, first, first, "`$ cd /`")),
+        None => return Err(ParseError::new(1, 1, "`$ cd /`", "")),
+    }
+
+    let tree_root = Rc::new(ElfFile {
+        name: "/".to_string(),
+        size: -1,
+        parent: None,
+        total_size: Cell::new(0),
+        depth: 0,
+    });
+
+    let mut file_tree: Vec<Rc<ElfFile>> = vec![tree_root.clone()];
+
+    let mut current_directory = file_tree[0].clone();
+
+    for (i, item) in history.iter().enumerate().skip(1) {
+        match get_command_type(item) {
+            HistoryItemType::CD => {
+                let Some(dir_name) = item.split_whitespace().nth(2) else {
+                    return Err(ParseError::at_end_of_line(i + 1, item, "a directory name"));
+                };
+                if let Some(result) = execute_cd(item, current_directory.clone()) {
+                    current_directory = Rc::new(result);
+                    file_tree.push(current_directory.clone());
+                } else if let Some(parent) = current_directory.parent.clone() {
+                    current_directory = parent
+                } else {
+                    return Err(ParseError::at_token(
+                        i + 1,
+                        item,
+                        dir_name,
+                        "a subdirectory name (`/` has no parent)",
+                    ));
+                }
+            }
+            HistoryItemType::File => {
+                if !item.starts_with("dir") {
+                    let mut split_item = item.split_whitespace();
+                    let size = match split_item.next() {
+                        Some(size) => parse_token::<u64>(i + 1, item, size, "a file size")?
+                            .try_into()
+                            .map_err(|_| ParseError::at_token(i + 1, item, size, "a file size"))?,
+                        None => return Err(ParseError::new(i + 1, 1, "a file size", "")),
+                    };
+                    let Some(name) = split_item.next() else {
+                        return Err(ParseError::at_end_of_line(i + 1, item, "a file name"));
+                    };
+
+                    let new_file = ElfFile {
+                        size,
+                        name: name.to_string(),
+                        parent: Some(current_directory.clone()),
+                        total_size: Cell::new(0),
+                        depth: current_directory.depth + 1,
+                    };
+
+                    let file_arc = Rc::new(new_file);
+                    file_tree.push(file_arc.clone());
+                    let mut parent = file_arc.parent.clone().unwrap();
+                    while !parent.is_root() {
+                        parent
+                            .total_size
+                            .set(parent.total_size.get() + file_arc.clone().size);
+                        parent = parent.clone().parent.clone().unwrap()
+                    }
+                    if parent.is_root() {
+                        parent
+                            .total_size
+                            .set(parent.total_size.get() + file_arc.clone().size)
+                    }
+                }
+            }
+            HistoryItemType::LS => (),
+        }
+    }
+
+    Ok(file_tree)
+}
+
+pub enum HistoryItemType {
+    CD,
+    LS,
+    File,
+}
+
+pub fn execute_cd(command: &str, current_dir: Rc<ElfFile>) -> Option<ElfFile> {
+    let dir_name = command.split_whitespace().last().unwrap();
+    if dir_name != ".." {
+        let new_file = ElfFile {
+            name: dir_name.to_string(),
+            size: -1,
+            parent: Some(current_dir.clone()),
+            total_size: Cell::new(0),
+            depth: current_dir.clone().depth + 1,
+        };
+        Some(new_file)
+    } else {
+        None
+    }
+}
+
+pub fn get_command_type(item: &str) -> HistoryItemType {
+    if item.starts_with("$ cd") {
+        return HistoryItemType::CD;
+    }
+    if item.starts_with("$ ls") {
+        return HistoryItemType::LS;
+    }
+    HistoryItemType::File
+}
@@ -0,0 +1,26 @@
+use common::{testing, Solution};
+use seventh::NoSpaceLeftOnDevice;
+
+#[test]
+fn example() {
+    testing::assert_answers::<NoSpaceLeftOnDevice>(include_str!("../example"), 95437, 24933642);
+}
+
+#[test]
+fn puzzle_input() {
+    testing::assert_answers_file::<NoSpaceLeftOnDevice>(env!("CARGO_MANIFEST_DIR"));
+}
+
+#[test]
+fn invalid_file_sizes() {
+    for size in ["-5", "9223372036854775808", "5x"] {
+        let input = format!("$ cd /\n$ ls\n{} a.txt", size);
+        let Err(error) = NoSpaceLeftOnDevice::parse(&input) else {
+            panic!("{} should not be a file size", size);
+        };
+        assert_eq!(
+            (error.line, error.column, error.found.as_str()),
+            (3, 1, size)
+        );
+    }
+}
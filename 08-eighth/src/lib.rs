@@ -0,0 +1,296 @@
+use colored::*;
+use common::{Answer, ParseError, Solution};
+use log::{debug, log_enabled, Level};
+use std::fmt::Display;
+
+pub const MIN_POS: usize = 0;
+pub struct Pos {
+  pub x: usize,
+  pub y: usize,
+}
+impl Pos {
+  pub fn pos_after_move(&self, direction: &Direction) -> Pos {
+    match direction {
+      Direction::Left => Pos {
+        x: self.x - 1,
+        y: self.y,
+      },
+      Direction::Top => Pos {
+        x: self.x,
+        y: self.y - 1,
+      },
+      Direction::Right => Pos {
+        x: self.x + 1,
+        y: self.y,
+      },
+      Direction::Bottom => Pos {
+        x: self.x,
+        y: self.y + 1,
+      },
+    }
+  }
+}
+impl PartialEq for Pos {
+  fn eq(&self, other: &Self) -> bool {
+    self.x == other.x && self.y == other.y
+  }
+}
+pub struct Tree {
+  pub pos: Pos,
+  pub height: usize,
+}
+
+#[derive(Debug)]
+pub enum Direction {
+  Left,
+  Top,
+  Right,
+  Bottom,
+}
+
+pub fn get_opposite_direction(direction: &Direction) -> Direction {
+  match direction {
+    Direction::Left => Direction::Right,
+    Direction::Top => Direction::Bottom,
+    Direction::Right => Direction::Left,
+    Direction::Bottom => Direction::Top,
+  }
+}
+
+impl Display for Tree {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "x:{}, y:{}, h:{}", self.pos.x, self.pos.y, self.height)
+  }
+}
+
+/** trees row by row, in a square grid */
+pub type TreeGrid = Vec<Tree>;
+
+pub trait TreeGridOperations {
+  fn get_width(&self) -> usize;
+  fn get_tree(&self, tree_pos: &Pos) -> Option<&Tree>;
+  fn has_neighbour(&self, tree_pos: &Pos, direction: &Direction) -> bool;
+  fn get_neighbour(&self, tree_pos: &Pos, direction: &Direction) -> Option<&Tree>;
+  fn is_tree_on_edge(&self, tree_pos: &Pos, direction: &Direction) -> bool;
+  fn calculate_scenic_score(&self, tree_pos: &Pos, direction: &Direction) -> usize;
+  fn total_scenic_score(&self, tree_pos: &Pos) -> usize;
+  fn get_visible_trees(&self) -> usize;
+}
+
+impl TreeGridOperations for TreeGrid {
+  fn get_width(&self) -> usize {
+    (self.len() as f64).sqrt() as usize - 1
+  }
+  fn get_tree(&self, tree_pos: &Pos) -> Option<&Tree> {
+    self.iter().find(|t| tree_pos == &t.pos)
+  }
+
+  fn has_neighbour(&self, tree_pos: &Pos, direction: &Direction) -> bool {
+    match direction {
+      Direction::Left => tree_pos.x > MIN_POS,
+      Direction::Top => tree_pos.y > MIN_POS,
+      Direction::Right => tree_pos.x < self.get_width(),
+      Direction::Bottom => tree_pos.y < self.get_width(),
+    }
+  }
+
+  fn get_neighbour(&self, tree_pos: &Pos, direction: &Direction) -> Option<&Tree> {
+    if self.has_neighbour(tree_pos, direction) {
+      return self.get_tree(&tree_pos.pos_after_move(direction));
+    }
+    None
+  }
+
+  fn is_tree_on_edge(&self, tree_pos: &Pos, direction: &Direction) -> bool {
+    match direction {
+      Direction::Left => !self.has_neighbour(tree_pos, direction),
+      Direction::Top => !self.has_neighbour(tree_pos, direction),
+      Direction::Right => !self.has_neighbour(tree_pos, direction),
+      Direction::Bottom => !self.has_neighbour(tree_pos, direction),
+    }
+  }
+
+  fn get_visible_trees(&self) -> usize {
+    const ALL_DIRECTIONS: [Direction; 4] = [
+      Direction::Left,
+      Direction::Top,
+      Direction::Right,
+      Direction::Bottom,
+    ];
+
+    let mut visible_trees: Vec<&Tree> = Vec::new();
+    for direction in ALL_DIRECTIONS {
+      for tree in self
+        .iter()
+        .filter(|t| self.is_tree_on_edge(&t.pos, &direction))
+      {
+        if visible_trees.iter().find(|t| tree.pos == t.pos).is_none() {
+          visible_trees.push(tree);
+        }
+        let mut current: &Tree = tree;
+        let mut highest: &Tree = tree;
+        while let Some(neigh) =
+          self.get_neighbour(&current.pos, &get_opposite_direction(&direction))
+        {
+          if neigh.height > highest.height
+            && visible_trees.iter().find(|t| neigh.pos == t.pos).is_none()
+          {
+            visible_trees.push(neigh);
+          }
+          current = neigh;
+          if current.height > highest.height {
+            highest = current;
+          }
+        }
+      }
+    }
+
+    if log_enabled!(Level::Debug) {
+      let mut row = String::new();
+      for t in self {
+        if visible_trees.iter().find(|vt| vt.pos == t.pos).is_some() {
+          row.push_str(&t.height.to_string());
+        } else {
+          row.push('▒');
+        }
+        if t.pos.x == self.get_width() {
+          debug!("{}", row);
+          row.clear();
+        }
+      }
+    }
+    visible_trees.len()
+  }
+
+  fn calculate_scenic_score(&self, tree_pos: &Pos, direction: &Direction) -> usize {
+    let mut score = 0;
+    let mut current: &Tree = self.get_tree(tree_pos).unwrap();
+    let start: &Tree = self.get_tree(tree_pos).unwrap();
+    while let Some(neigh) = self.get_neighbour(&current.pos, direction) {
+      score += 1;
+      if neigh.height >= start.height {
+        break;
+      }
+      current = neigh;
+    }
+
+    score
+  }
+
+  fn total_scenic_score(&self, tree_pos: &Pos) -> usize {
+    if self.is_tree_on_edge(tree_pos, &Direction::Bottom)
+      || self.is_tree_on_edge(tree_pos, &Direction::Left)
+      || self.is_tree_on_edge(tree_pos, &Direction::Right)
+      || self.is_tree_on_edge(tree_pos, &Direction::Top)
+    {
+      return 0;
+    }
+
+    self.calculate_scenic_score(tree_pos, &Direction::Bottom)
+      * self.calculate_scenic_score(tree_pos, &Direction::Left)
+      * self.calculate_scenic_score(tree_pos, &Direction::Right)
+      * self.calculate_scenic_score(tree_pos, &Direction::Top)
+  }
+}
+
+pub fn get_height_color(height: &usize, score: &usize) -> ColoredString {
+  match score {
+    0 => (" ".to_owned() + &height.to_string()).on_blue(),
+    1 => (" ".to_owned() + &height.to_string()).on_bright_blue(),
+    2 => (" ".to_owned() + &height.to_string()).on_cyan(),
+    3 => (" ".to_owned() + &height.to_string()).on_bright_cyan(),
+    4..=10 => (" ".to_owned() + &height.to_string()).on_green(),
+    11..=50_000 => (" ".to_owned() + &height.to_string()).on_bright_green(),
+    50_001..=100_000 => (" ".to_owned() + &height.to_string()).on_yellow(),
+    100_001..=200_000 => (" ".to_owned() + &height.to_string()).on_bright_yellow(),
+    200_001..=300_000 => (" ".to_owned() + &height.to_string()).on_bright_red(),
+    300_001.. => (" ".to_owned() + &height.to_string()).on_red(),
+  }
+}
+
+pub struct TreetopTreeHouse;
+
+impl Solution for TreetopTreeHouse {
+  type Input = TreeGrid;
+
+  fn parse(input: &str) -> Result<Self::Input, ParseError> {
+    let mut trees: TreeGrid = Vec::new();
+    let lines: Vec<&str> = input.lines().collect();
+    let first = lines.first().copied().unwrap_or("");
+    if first.is_empty() {
+      return Err(ParseError::at_end_of_line(
+        1,
+        first,
+        "a row of tree heights",
+      ));
+    }
+    // the first row sets the size of the square grid
+    let size = first.chars().count();
+    for (y, line) in lines.iter().enumerate() {
+      if y == size {
+        let expected = format!("the end of the square grid of {} rows", size);
+        return Err(ParseError::at_token(y + 1, line, line, &expected));
+      }
+      if line.chars().count() != size {
+        let expected = format!("a row of {} trees, as many as in the first row", size);
+        return Err(match line.char_indices().nth(size) {
+          Some((i, _)) => ParseError::at_token(y + 1, line, &line[i..], &expected),
+          None => ParseError::at_end_of_line(y + 1, line, &expected),
+        });
+      }
+      for (x, (i, c)) in line.char_indices().enumerate() {
+        let Some(height) = c.to_digit(10) else {
+          let found = &line[i..i + c.len_utf8()];
+          return Err(ParseError::at_token(
+            y + 1,
+            line,
+            found,
+            "a tree height digit",
+          ));
+        };
+        let tree = Tree {
+          pos: Pos { x, y },
+          height: height as usize,
+        };
+        trees.push(tree)
+      }
+    }
+    if lines.len() < size {
+      let expected = format!(
+        "{} more row(s) for a square grid of {} rows",
+        size - lines.len(),
+        size
+      );
+      return Err(ParseError::new(lines.len() + 1, 1, &expected, ""));
+    }
+    Ok(trees)
+  }
+
+  fn part1(trees: &Self::Input) -> Answer {
+    trees.get_visible_trees().into()
+  }
+
+  fn part2(trees: &Self::Input) -> Answer {
+    let scores: Vec<(&Tree, usize)> = trees
+      .iter()
+      .map(|t| (t, trees.total_scenic_score(&t.pos)))
+      .collect();
+    let best = scores
+      .iter()
+      .max_by_key(|t| t.1)
+      .expect("parsing rejects empty grids")
+      .1;
+    if log_enabled!(Level::Debug) {
+      let mut row = String::new();
+      for t in scores {
+        row.push_str(&get_height_color(&t.0.height, &t.1).to_string());
+
+        if t.0.pos.x == trees.get_width() {
+          debug!("{}", row);
+          row.clear();
+        }
+      }
+    }
+    best.into()
+  }
+}
@@ -0,0 +1,29 @@
+use common::{testing, Solution};
+use eighth::TreetopTreeHouse;
+
+#[test]
+fn example() {
+  testing::assert_answers::<TreetopTreeHouse>(include_str!("../example"), 21, 8);
+}
+
+#[test]
+fn puzzle_input() {
+  testing::assert_answers_file::<TreetopTreeHouse>(env!("CARGO_MANIFEST_DIR"));
+}
+
+#[test]
+fn malformed_grids() {
+  let located = |input: &str| {
+    let Err(error) = TreetopTreeHouse::parse(input) else {
+      panic!("{:?} should not parse", input);
+    };
+    (error.line, error.column)
+  };
+  assert_eq!(located(""), (1, 1));
+  assert_eq!(located("\n12\n34"), (1, 1));
+  assert_eq!(located("123\n456"), (3, 1));
+  assert_eq!(located("123\n45\n789"), (2, 3));
+  assert_eq!(located("123\n4567\n789"), (2, 4));
+  assert_eq!(located("12\n34\n56"), (3, 1));
+  assert_eq!(located("12\n3x"), (2, 2));
+}
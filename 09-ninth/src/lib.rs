@@ -0,0 +1,180 @@
+use common::{parse_token, Answer, ParseError, Solution};
+use log::trace;
+use std::fmt::Display;
+
+#[derive(Debug)]
+pub enum Direction {
+  Up,
+  Left,
+  Down,
+  Right,
+}
+
+pub fn opposite(direction: &Direction) -> Direction {
+  match direction {
+    Direction::Left => Direction::Right,
+    Direction::Up => Direction::Down,
+    Direction::Right => Direction::Left,
+    Direction::Down => Direction::Up,
+  }
+}
+
+#[derive(Debug)]
+pub struct Move {
+  pub direction: Direction,
+  pub distance: usize,
+}
+
+pub struct Point {
+  pub x: isize,
+  pub y: isize,
+}
+impl Display for Point {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "({};{})", self.x, self.y)
+  }
+}
+impl Point {
+  pub fn pos_after_move(&self, direction: &Direction) -> Point {
+    match direction {
+      Direction::Left => Point {
+        x: self.x - 1,
+        y: self.y,
+      },
+      Direction::Up => Point {
+        x: self.x,
+        y: self.y + 1,
+      },
+      Direction::Right => Point {
+        x: self.x + 1,
+        y: self.y,
+      },
+      Direction::Down => Point {
+        x: self.x,
+        y: self.y - 1,
+      },
+    }
+  }
+
+  pub fn distance_from(&self, other: &Point) -> usize {
+    let dx = (other.x - self.x) as f32;
+    let dy = (other.y - self.y) as f32;
+    (dx * dx + dy * dy).sqrt().round() as usize
+  }
+
+  /** one step towards `head`, unless already touching it */
+  pub fn follow(&self, head: &Point) -> Point {
+    let dx = head.x - self.x;
+    let dy = head.y - self.y;
+    if dx.abs() <= 1 && dy.abs() <= 1 {
+      return Point {
+        x: self.x,
+        y: self.y,
+      };
+    }
+    Point {
+      x: self.x + dx.signum(),
+      y: self.y + dy.signum(),
+    }
+  }
+}
+impl PartialEq for Point {
+  fn eq(&self, other: &Self) -> bool {
+    self.x == other.x && self.y == other.y
+  }
+}
+impl Eq for Point {}
+
+pub struct RopeBridge;
+
+impl Solution for RopeBridge {
+  type Input = Vec<Move>;
+
+  fn parse(input: &str) -> Result<Self::Input, ParseError> {
+    input
+      .lines()
+      .enumerate()
+      .map(|(i, line)| parse_move(i + 1, line))
+      .collect()
+  }
+
+  fn part1(moves: &Self::Input) -> Answer {
+    let mut head = Point { x: 0, y: 0 };
+    let mut tail = Point { x: 0, y: 0 };
+    let mut visited: Vec<Point> = vec![Point { x: 0, y: 0 }];
+    trace!("H:{} T:{} d:{}", head, tail, head.distance_from(&tail));
+    for parsed_move in moves {
+      trace!("{:?} ====== ", parsed_move);
+      for _ in 0..parsed_move.distance {
+        head = head.pos_after_move(&parsed_move.direction);
+        if head.distance_from(&tail) > 1 {
+          trace!("tail move");
+          tail = head.pos_after_move(&opposite(&parsed_move.direction));
+          if visited.iter().find(|p| p == &&tail).is_none() {
+            visited.push(Point {
+              x: tail.x,
+              y: tail.y,
+            });
+          }
+        }
+        trace!("H:{} T:{} d:{}", head, tail, head.distance_from(&tail));
+      }
+    }
+
+    for p in &visited {
+      trace!("{}", p);
+    }
+    visited.len().into()
+  }
+
+  fn part2(moves: &Self::Input) -> Answer {
+    const KNOTS: usize = 10;
+    let mut knots: Vec<Point> = (0..KNOTS).map(|_| Point { x: 0, y: 0 }).collect();
+    let mut visited: Vec<Point> = vec![Point { x: 0, y: 0 }];
+    for parsed_move in moves {
+      for _ in 0..parsed_move.distance {
+        knots[0] = knots[0].pos_after_move(&parsed_move.direction);
+        for i in 1..KNOTS {
+          knots[i] = knots[i].follow(&knots[i - 1]);
+        }
+        let tail = &knots[KNOTS - 1];
+        if visited.iter().find(|p| p == &tail).is_none() {
+          visited.push(Point {
+            x: tail.x,
+            y: tail.y,
+          });
+        }
+      }
+    }
+    visited.len().into()
+  }
+}
+
+pub fn parse_move(line_number: usize, line: &str) -> Result<Move, ParseError> {
+  let mut split = line.split_ascii_whitespace();
+  let Some(raw_direction) = split.next() else {
+    return Err(ParseError::new(line_number, 1, "`U`, `R`, `D` or `L`", ""));
+  };
+  let direction = match raw_direction {
+    "U" => Direction::Up,
+    "R" => Direction::Right,
+    "D" => Direction::Down,
+    "L" => Direction::Left,
+    &_ => {
+      return Err(ParseError::at_token(
+        line_number,
+        line,
+        raw_direction,
+        "`U`, `R`, `D` or `L`",
+      ))
+    }
+  };
+  let distance = match split.next() {
+    Some(raw_distance) => parse_token::<usize>(line_number, line, raw_distance, "a distance")?,
+    None => return Err(ParseError::at_end_of_line(line_number, line, "a distance")),
+  };
+  Ok(Move {
+    direction,
+    distance,
+  })
+}
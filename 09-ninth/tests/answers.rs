@@ -0,0 +1,28 @@
+use common::{testing, Solution};
+use ninth::RopeBridge;
+
+#[test]
+fn example() {
+  testing::assert_answers::<RopeBridge>(include_str!("../example"), 13, 1);
+}
+
+#[test]
+fn larger_example() {
+  testing::assert_answer::<RopeBridge>(include_str!("../larger-example"), 2, 36);
+}
+
+#[test]
+fn puzzle_input() {
+  testing::assert_answers_file::<RopeBridge>(env!("CARGO_MANIFEST_DIR"));
+}
+
+#[test]
+fn negative_distances() {
+  let Err(error) = RopeBridge::parse("R 4\nU -3") else {
+    panic!("`U -3` should not parse");
+  };
+  assert_eq!(
+    (error.line, error.column, error.found.as_str()),
+    (2, 3, "-3")
+  );
+}
@@ -0,0 +1,123 @@
+use common::{parse_token, Answer, ParseError, Solution};
+
+#[derive(Debug)]
+pub enum InstructionType {
+  Noop,
+  Addx(isize),
+}
+
+impl PartialEq for InstructionType {
+  fn eq(&self, other: &Self) -> bool {
+    match (self, other) {
+      (InstructionType::Noop, InstructionType::Noop) => true,
+      (InstructionType::Noop, InstructionType::Addx(_)) => false,
+      (InstructionType::Addx(_), InstructionType::Noop) => false,
+      (InstructionType::Addx(value), InstructionType::Addx(other_value)) => value == other_value,
+    }
+  }
+}
+
+#[derive(Debug)]
+pub struct Instruction {
+  pub t: InstructionType,
+}
+
+/** one instruction per cycle: `addx` takes a `Noop` cycle before the actual add */
+pub fn parse_instruction(line_number: usize, line: &str) -> Result<Vec<Instruction>, ParseError> {
+  let mut split = line.split_whitespace();
+  match split.next() {
+    Some("addx") => {
+      let value = match split.next() {
+        Some(raw_value) => parse_token::<isize>(line_number, line, raw_value, "a number")?,
+        None => return Err(ParseError::at_end_of_line(line_number, line, "a number")),
+      };
+
+      Ok(vec![
+        Instruction {
+          t: InstructionType::Noop,
+        },
+        Instruction {
+          t: InstructionType::Addx(value),
+        },
+      ])
+    }
+    Some("noop") => Ok(vec![Instruction {
+      t: InstructionType::Noop,
+    }]),
+    Some(found) => Err(ParseError::at_token(
+      line_number,
+      line,
+      found,
+      "`addx` or `noop`",
+    )),
+    None => Err(ParseError::new(line_number, 1, "`addx` or `noop`", "")),
+  }
+}
+
+pub fn first_interrupt(x: &isize, cycles: &isize) -> isize {
+  if cycles % 40 == 20 {
+    return x * cycles;
+  }
+  0
+}
+
+pub fn second_interrupt(x: &isize, row: &isize) -> char {
+  if row == x || row == &(x - 1) || row == &(x + 1) {
+    '#'
+  } else {
+    '.'
+  }
+}
+
+/** `(signal strength sum, CRT screen)` */
+pub fn run_program(program: &[Instruction]) -> (isize, String) {
+  let mut cycles: isize = 0;
+  let mut x: isize = 1;
+  let mut signal_sums = 0;
+  let mut row_i: isize = 0;
+  let mut screen = String::new();
+  for instruction in program {
+    if let InstructionType::Addx(value) = instruction.t {
+      cycles += 1;
+
+      signal_sums += first_interrupt(&x, &cycles);
+      screen.push(second_interrupt(&x, &row_i));
+      x += value;
+    } else {
+      cycles += 1;
+
+      signal_sums += first_interrupt(&x, &cycles);
+      screen.push(second_interrupt(&x, &row_i));
+    }
+
+    row_i += 1;
+    if cycles % 40 == 0 {
+      row_i = 0;
+      screen.push('\n');
+    }
+  }
+  (signal_sums, screen)
+}
+
+pub struct CathodeRayTube;
+
+impl Solution for CathodeRayTube {
+  /** the program, with `addx` already split into its two cycles */
+  type Input = Vec<Instruction>;
+
+  fn parse(input: &str) -> Result<Self::Input, ParseError> {
+    let mut program = Vec::new();
+    for (i, line) in input.lines().enumerate() {
+      program.extend(parse_instruction(i + 1, line)?);
+    }
+    Ok(program)
+  }
+
+  fn part1(program: &Self::Input) -> Answer {
+    run_program(program).0.into()
+  }
+
+  fn part2(program: &Self::Input) -> Answer {
+    run_program(program).1.into()
+  }
+}
@@ -0,0 +1,23 @@
+use common::testing;
+use tenth::CathodeRayTube;
+
+#[test]
+fn example() {
+  testing::assert_answers::<CathodeRayTube>(
+    include_str!("../example"),
+    13140,
+    concat!(
+      "##..##..##..##..##..##..##..##..##..##..\n",
+      "###...###...###...###...###...###...###.\n",
+      "####....####....####....####....####....\n",
+      "#####.....#####.....#####.....#####.....\n",
+      "######......######......######......####\n",
+      "#######.......#######.......#######.....\n",
+    ),
+  );
+}
+
+#[test]
+fn puzzle_input() {
+  testing::assert_answers_file::<CathodeRayTube>(env!("CARGO_MANIFEST_DIR"));
+}
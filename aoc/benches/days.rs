@@ -0,0 +1,54 @@
+//! Parse, part 1 and part 2 timings of every day, on its example and on its puzzle input.
+//!
+//! `cargo bench -p aoc -- --save-baseline <name>` records a run,
+//! `cargo bench -p aoc -- --baseline <name>` compares against it.
+
+use common::{normalize, Solution};
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use std::{fs, path::PathBuf};
+
+fn bench_day<S: Solution>(c: &mut Criterion, dir: &str) {
+    let mut group = c.benchmark_group(dir);
+    group.sample_size(10);
+    for file in ["example", "input"] {
+        let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("..")
+            .join(dir)
+            .join(file);
+        let Ok(content) = fs::read_to_string(&path) else {
+            continue;
+        };
+        let input = normalize(&content);
+        let parsed = match S::parse(&input) {
+            Ok(parsed) => parsed,
+            Err(error) => panic!("{}", error.render(&path.display().to_string(), &input)),
+        };
+
+        group.bench_function(format!("{}/parse", file), |b| {
+            b.iter(|| S::parse(black_box(&input)))
+        });
+        group.bench_function(format!("{}/part1", file), |b| {
+            b.iter(|| S::part1(black_box(&parsed)))
+        });
+        group.bench_function(format!("{}/part2", file), |b| {
+            b.iter(|| S::part2(black_box(&parsed)))
+        });
+    }
+    group.finish();
+}
+
+fn days(c: &mut Criterion) {
+    bench_day::<first::CalorieCounting>(c, "01-first");
+    bench_day::<second::RockPaperScissors>(c, "02-second");
+    bench_day::<third::RucksackReorganization>(c, "03-third");
+    bench_day::<fourth::CampCleanup>(c, "04-fourth");
+    bench_day::<fifth::SupplyStacks>(c, "05-fifth");
+    bench_day::<sixth::TuningTrouble>(c, "06-sixth");
+    bench_day::<seventh::NoSpaceLeftOnDevice>(c, "07-seventh");
+    bench_day::<eighth::TreetopTreeHouse>(c, "08-eighth");
+    bench_day::<ninth::RopeBridge>(c, "09-ninth");
+    bench_day::<tenth::CathodeRayTube>(c, "10-tenth");
+}
+
+criterion_group!(benches, days);
+criterion_main!(benches);
@@ -0,0 +1,199 @@
+mod logger;
+mod report;
+
+use clap::{Parser, Subcommand};
+use common::{InputSource, ParseError, Solution};
+use report::{print_table, DayReport, Format, Summary};
+use serde_json::json;
+use std::{path::PathBuf, process, time::Instant};
+
+/** day number, input content and part to run */
+type DaySolver = fn(u8, &str, Option<u8>) -> Result<DayReport, ParseError>;
+
+struct Day {
+    /** directory holding the day's crate and its `input` */
+    dir: &'static str,
+    solve: DaySolver,
+}
+
+fn solve<S: Solution>(day: u8, input: &str, part: Option<u8>) -> Result<DayReport, ParseError> {
+    let start = Instant::now();
+    let parsed = S::parse(input)?;
+    let parse_time = start.elapsed();
+
+    let mut report = DayReport {
+        day,
+        parse_time,
+        part1: None,
+        part2: None,
+    };
+    if part != Some(2) {
+        let start = Instant::now();
+        let answer = S::part1(&parsed);
+        report.part1 = Some((answer, start.elapsed()));
+    }
+    if part != Some(1) {
+        let start = Instant::now();
+        let answer = S::part2(&parsed);
+        report.part2 = Some((answer, start.elapsed()));
+    }
+    Ok(report)
+}
+
+const DAYS: [Day; 10] = [
+    Day {
+        dir: "01-first",
+        solve: solve::<first::CalorieCounting>,
+    },
+    Day {
+        dir: "02-second",
+        solve: solve::<second::RockPaperScissors>,
+    },
+    Day {
+        dir: "03-third",
+        solve: solve::<third::RucksackReorganization>,
+    },
+    Day {
+        dir: "04-fourth",
+        solve: solve::<fourth::CampCleanup>,
+    },
+    Day {
+        dir: "05-fifth",
+        solve: solve::<fifth::SupplyStacks>,
+    },
+    Day {
+        dir: "06-sixth",
+        solve: solve::<sixth::TuningTrouble>,
+    },
+    Day {
+        dir: "07-seventh",
+        solve: solve::<seventh::NoSpaceLeftOnDevice>,
+    },
+    Day {
+        dir: "08-eighth",
+        solve: solve::<eighth::TreetopTreeHouse>,
+    },
+    Day {
+        dir: "09-ninth",
+        solve: solve::<ninth::RopeBridge>,
+    },
+    Day {
+        dir: "10-tenth",
+        solve: solve::<tenth::CathodeRayTube>,
+    },
+];
+
+#[derive(Parser)]
+#[command(about = "Advent of Code 2022 solutions runner")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+    /// Only print answers and errors, even with `-v`
+    #[arg(short, long, global = true)]
+    quiet: bool,
+    /// Print the days' debug output, twice for step by step traces
+    #[arg(short, long, global = true, action = clap::ArgAction::Count)]
+    verbose: u8,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run one day, or every day with `--all`
+    Run {
+        /// Day number, from 1 to 10
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=10), required_unless_present = "all")]
+        day: Option<u8>,
+        /// Run every day
+        #[arg(long, conflicts_with_all = ["day", "input"])]
+        all: bool,
+        /// Only run the given part
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// Puzzle input file, `-` for stdin, defaults to the day's `input` file
+        #[arg(long)]
+        input: Option<String>,
+        /// Output format, diagnostics always go to stderr
+        #[arg(long, value_enum, default_value_t = Format::Text)]
+        format: Format,
+    },
+}
+
+fn default_input(day: &Day) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("..")
+        .join(day.dir)
+        .join("input")
+}
+
+/** the day's report, or `None` once the reason why there is none is printed */
+fn run_day(number: u8, part: Option<u8>, input: Option<String>) -> Option<DayReport> {
+    let day = &DAYS[number as usize - 1];
+    let source = match input {
+        Some(arg) => InputSource::from_arg(&arg),
+        None => InputSource::File(default_input(day)),
+    };
+    let content = match source.read() {
+        Ok(content) => content,
+        Err(error) => {
+            eprintln!("day {}: {}", number, error);
+            return None;
+        }
+    };
+    match (day.solve)(number, &content, part) {
+        Ok(report) => Some(report),
+        Err(error) => {
+            eprintln!(
+                "day {}: {}",
+                number,
+                error.render(&source.to_string(), &content)
+            );
+            None
+        }
+    }
+}
+
+fn main() {
+    let cli = Cli::parse();
+    logger::init(cli.quiet, cli.verbose);
+    let success = match cli.command {
+        Command::Run {
+            day,
+            all,
+            part,
+            input,
+            format,
+        } => {
+            let numbers = if all {
+                1..=DAYS.len() as u8
+            } else {
+                let number = day.expect("clap requires a day without --all");
+                number..=number
+            };
+            let mut success = true;
+            let mut reports = Vec::new();
+            for number in numbers {
+                match run_day(number, part, input.clone()) {
+                    Some(report) => reports.push(report),
+                    None => success = false,
+                }
+            }
+
+            match format {
+                Format::Text => reports.iter().for_each(DayReport::print_text),
+                Format::Json if all => {
+                    let days: Vec<_> = reports.iter().map(DayReport::to_json).collect();
+                    let summary = Summary::new(&reports).to_json();
+                    println!("{}", json!({ "days": days, "summary": summary }));
+                }
+                Format::Json => reports
+                    .iter()
+                    .for_each(|report| println!("{}", report.to_json())),
+                Format::Table => print_table(&reports),
+            }
+            success
+        }
+    };
+    if !success {
+        process::exit(1);
+    }
+}
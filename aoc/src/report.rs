@@ -0,0 +1,167 @@
+use clap::ValueEnum;
+use common::Answer;
+use serde_json::{json, Value};
+use std::{fs, time::Duration};
+
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    /// Answers as `day N part P: answer` lines
+    Text,
+    /// One JSON object per day, `{day, part1, part2, timings}`,
+    /// under `days` next to a `summary` with `--all`
+    Json,
+    /// Answers and timings of each day, then a summary
+    Table,
+}
+
+/** answers of the requested parts, and how long it took to get them */
+pub struct DayReport {
+    pub day: u8,
+    pub parse_time: Duration,
+    pub part1: Option<(Answer, Duration)>,
+    pub part2: Option<(Answer, Duration)>,
+}
+
+impl DayReport {
+    /** parsing and solving time */
+    pub fn total_time(&self) -> Duration {
+        [&self.part1, &self.part2]
+            .into_iter()
+            .flatten()
+            .map(|(_, time)| *time)
+            .sum::<Duration>()
+            + self.parse_time
+    }
+
+    pub fn print_text(&self) {
+        for (part_number, part) in [(1, &self.part1), (2, &self.part2)] {
+            let Some((answer, _)) = part else {
+                continue;
+            };
+            let answer = answer.to_string();
+            if answer.contains('\n') {
+                print!("day {} part {}:\n{}", self.day, part_number, answer)
+            } else {
+                println!("day {} part {}: {}", self.day, part_number, answer)
+            }
+        }
+    }
+
+    pub fn to_json(&self) -> Value {
+        json!({
+            "day": self.day,
+            "part1": self.part1.as_ref().map(|(answer, _)| answer_to_json(answer)),
+            "part2": self.part2.as_ref().map(|(answer, _)| answer_to_json(answer)),
+            "timings": {
+                "parse_ms": millis(self.parse_time),
+                "part1_ms": self.part1.as_ref().map(|(_, time)| millis(*time)),
+                "part2_ms": self.part2.as_ref().map(|(_, time)| millis(*time)),
+            },
+        })
+    }
+}
+
+/** totals over all the days that were run */
+pub struct Summary {
+    pub total_time: Duration,
+    /** day number and total time */
+    pub slowest: Option<(u8, Duration)>,
+    /** peak resident memory of the whole run, only known on Linux */
+    pub peak_memory_kb: Option<u64>,
+}
+
+impl Summary {
+    pub fn new(reports: &[DayReport]) -> Self {
+        Summary {
+            total_time: reports.iter().map(DayReport::total_time).sum(),
+            slowest: reports
+                .iter()
+                .map(|report| (report.day, report.total_time()))
+                .max_by_key(|(_, time)| *time),
+            peak_memory_kb: peak_memory_kb(),
+        }
+    }
+
+    pub fn to_json(&self) -> Value {
+        json!({
+            "total_ms": millis(self.total_time),
+            "slowest_day": self.slowest.map(|(day, _)| day),
+            "slowest_ms": self.slowest.map(|(_, time)| millis(time)),
+            "peak_memory_kb": self.peak_memory_kb,
+        })
+    }
+}
+
+/** one row per day, then the summary */
+pub fn print_table(reports: &[DayReport]) {
+    println!(
+        "{:>3}  {:<20} {:<20} {:>10} {:>10} {:>10} {:>10}",
+        "day", "part 1", "part 2", "parse", "part 1", "part 2", "total"
+    );
+    for report in reports {
+        println!(
+            "{:>3}  {:<20} {:<20} {:>10} {:>10} {:>10} {:>10}",
+            report.day,
+            table_answer(&report.part1),
+            table_answer(&report.part2),
+            format_millis(Some(report.parse_time)),
+            format_millis(report.part1.as_ref().map(|(_, time)| *time)),
+            format_millis(report.part2.as_ref().map(|(_, time)| *time)),
+            format_millis(Some(report.total_time())),
+        );
+    }
+
+    let summary = Summary::new(reports);
+    println!();
+    println!("total time   {}", format_millis(Some(summary.total_time)));
+    if let Some((day, time)) = summary.slowest {
+        println!("slowest day  {} ({})", day, format_millis(Some(time)));
+    }
+    if let Some(peak_memory_kb) = summary.peak_memory_kb {
+        println!("peak memory  {} kB", peak_memory_kb);
+    }
+}
+
+/** multi-line answers (e.g. a drawn screen) do not fit in a cell */
+fn table_answer(part: &Option<(Answer, Duration)>) -> String {
+    match part {
+        Some((answer, _)) => {
+            let answer = answer.to_string();
+            let lines = answer.lines().count();
+            if lines > 1 {
+                format!("<{} lines>", lines)
+            } else {
+                answer
+            }
+        }
+        None => "-".to_string(),
+    }
+}
+
+fn format_millis(duration: Option<Duration>) -> String {
+    match duration {
+        Some(duration) => format!("{:.3} ms", millis(duration)),
+        None => "-".to_string(),
+    }
+}
+
+/** `VmHWM` from `/proc/self/status` */
+fn peak_memory_kb() -> Option<u64> {
+    let status = fs::read_to_string("/proc/self/status").ok()?;
+    status
+        .lines()
+        .find_map(|line| line.strip_prefix("VmHWM:"))
+        .and_then(|value| value.trim().trim_end_matches("kB").trim().parse().ok())
+}
+
+fn answer_to_json(answer: &Answer) -> Value {
+    match answer {
+        Answer::Number(value) => json!(value),
+        Answer::Text(value) => json!(value),
+        Answer::Unavailable(reason) => json!({ "unavailable": reason }),
+    }
+}
+
+fn millis(duration: Duration) -> f64 {
+    duration.as_secs_f64() * 1000.0
+}
@@ -0,0 +1,68 @@
+use crate::{parse_token, ParseError};
+use std::str::{FromStr, Lines};
+
+/** Consecutive non-blank lines, with the 1-based line number of the first one */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Group<'a> {
+    pub first_line: usize,
+    pub lines: Vec<&'a str>,
+}
+
+impl<'a> Group<'a> {
+    /** each line with its 1-based line number */
+    pub fn numbered_lines(&self) -> impl Iterator<Item = (usize, &'a str)> + '_ {
+        (self.first_line..).zip(self.lines.iter().copied())
+    }
+}
+
+/**
+ * Iterator over the groups of lines separated by blank lines, see [`groups`].
+ * Several blank lines in a row separate groups like a single one.
+ */
+pub struct Groups<'a> {
+    lines: Lines<'a>,
+    line_number: usize,
+}
+
+impl<'a> Iterator for Groups<'a> {
+    type Item = Group<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut group: Option<Group<'a>> = None;
+        for line in self.lines.by_ref() {
+            self.line_number += 1;
+            if !line.is_empty() {
+                group
+                    .get_or_insert_with(|| Group {
+                        first_line: self.line_number,
+                        lines: Vec::new(),
+                    })
+                    .lines
+                    .push(line);
+            } else if group.is_some() {
+                break;
+            }
+        }
+        group
+    }
+}
+
+/** groups of lines separated by blank lines, the last one needs no trailing blank line */
+pub fn groups(input: &str) -> Groups<'_> {
+    Groups {
+        lines: input.lines(),
+        line_number: 0,
+    }
+}
+
+/** every line of every group parsed as a `T`, or the first line that is not `expected` */
+pub fn parse_groups<T: FromStr>(input: &str, expected: &str) -> Result<Vec<Vec<T>>, ParseError> {
+    groups(input)
+        .map(|group| {
+            group
+                .numbered_lines()
+                .map(|(line_number, line)| parse_token(line_number, line, line, expected))
+                .collect()
+        })
+        .collect()
+}
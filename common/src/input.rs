@@ -0,0 +1,95 @@
+use std::{
+    error::Error,
+    fmt::Display,
+    fs,
+    io::{self, BufRead, BufReader, Cursor, Read},
+    path::PathBuf,
+};
+
+/** Where a puzzle input is read from */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    File(PathBuf),
+    Stdin,
+    Text(String),
+}
+
+impl InputSource {
+    /** `-` is stdin, anything else is a file path */
+    pub fn from_arg(arg: &str) -> Self {
+        if arg == "-" {
+            InputSource::Stdin
+        } else {
+            InputSource::File(PathBuf::from(arg))
+        }
+    }
+
+    /** reads the whole input, normalised with [`normalize`] */
+    pub fn read(&self) -> Result<String, InputError> {
+        let content = match self {
+            InputSource::File(path) => fs::read_to_string(path),
+            InputSource::Stdin => {
+                let mut content = String::new();
+                io::stdin().read_to_string(&mut content).map(|_| content)
+            }
+            InputSource::Text(text) => Ok(text.clone()),
+        };
+        content
+            .map(|content| normalize(&content))
+            .map_err(|error| InputError {
+                path: PathBuf::from(self.to_string()),
+                error,
+            })
+    }
+
+    /** buffered reader over the raw input, to go through it without holding it all */
+    pub fn open(&self) -> Result<Box<dyn BufRead>, InputError> {
+        match self {
+            InputSource::File(path) => fs::File::open(path)
+                .map(|file| Box::new(BufReader::new(file)) as Box<dyn BufRead>)
+                .map_err(|error| InputError {
+                    path: path.clone(),
+                    error,
+                }),
+            InputSource::Stdin => Ok(Box::new(io::stdin().lock())),
+            InputSource::Text(text) => Ok(Box::new(Cursor::new(text.clone().into_bytes()))),
+        }
+    }
+}
+
+impl Display for InputSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputSource::File(path) => write!(f, "{}", path.display()),
+            InputSource::Stdin => write!(f, "<stdin>"),
+            InputSource::Text(_) => write!(f, "<text>"),
+        }
+    }
+}
+
+/** `\r\n` line endings become `\n`, trailing newlines are dropped */
+pub fn normalize(content: &str) -> String {
+    content
+        .replace("\r\n", "\n")
+        .trim_end_matches('\n')
+        .to_string()
+}
+
+/** An input that could not be read, with the path (`<stdin>` for stdin) it came from */
+#[derive(Debug)]
+pub struct InputError {
+    pub path: PathBuf,
+    pub error: io::Error,
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "cannot read {}: {}", self.path.display(), self.error)
+    }
+}
+
+impl Error for InputError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.error)
+    }
+}
@@ -0,0 +1,10 @@
+mod groups;
+mod input;
+mod parse_error;
+mod solution;
+pub mod testing;
+
+pub use groups::{groups, parse_groups, Group, Groups};
+pub use input::{normalize, InputError, InputSource};
+pub use parse_error::{column_of, parse_token, ParseError};
+pub use solution::{Answer, Solution};
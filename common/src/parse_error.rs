@@ -0,0 +1,103 @@
+use std::{error::Error, fmt::Display, str::FromStr};
+
+/**
+ * A malformed input, located by its 1-based `line` and `column`.
+ * An empty `found` means the line (or the input) ended too early.
+ */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub expected: String,
+    pub found: String,
+}
+
+impl ParseError {
+    pub fn new(line: usize, column: usize, expected: &str, found: &str) -> Self {
+        ParseError {
+            line,
+            column,
+            expected: expected.to_string(),
+            found: found.to_string(),
+        }
+    }
+
+    /** error on `token`, which must be a slice of `line_text` */
+    pub fn at_token(line: usize, line_text: &str, token: &str, expected: &str) -> Self {
+        ParseError::new(line, column_of(line_text, token), expected, token)
+    }
+
+    /** error right after the last char of `line_text` */
+    pub fn at_end_of_line(line: usize, line_text: &str, expected: &str) -> Self {
+        ParseError::new(line, line_text.chars().count() + 1, expected, "")
+    }
+
+    pub fn message(&self) -> String {
+        if self.found.is_empty() {
+            format!("expected {}, found end of line", self.expected)
+        } else {
+            format!("expected {}, found `{}`", self.expected, self.found)
+        }
+    }
+
+    /**
+     * rustc-like diagnostic pointing at the error in `input`:
+     *
+     * ```text
+     * error: expected a number, found `1x`
+     *  --> input:3:1
+     *   |
+     * 3 | 1x
+     *   | ^^
+     * ```
+     */
+    pub fn render(&self, source_name: &str, input: &str) -> String {
+        let line_text = input.lines().nth(self.line.saturating_sub(1)).unwrap_or("");
+        let number = self.line.to_string();
+        let gutter = " ".repeat(number.len());
+        let carets = "^".repeat(self.found.chars().count().max(1));
+        format!(
+            "error: {}\n{}--> {}:{}:{}\n{} |\n{} | {}\n{} | {}{}",
+            self.message(),
+            gutter,
+            source_name,
+            self.line,
+            self.column,
+            gutter,
+            number,
+            line_text,
+            gutter,
+            " ".repeat(self.column.saturating_sub(1)),
+            carets
+        )
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}: {}", self.line, self.column, self.message())
+    }
+}
+
+impl Error for ParseError {}
+
+/** 1-based char column of `token`, which must be a slice of `line_text` */
+pub fn column_of(line_text: &str, token: &str) -> usize {
+    let offset = (token.as_ptr() as usize).saturating_sub(line_text.as_ptr() as usize);
+    line_text
+        .get(..offset.min(line_text.len()))
+        .map_or(0, |before| before.chars().count())
+        + 1
+}
+
+/** parses `token`, a slice of `line_text`, or reports it as not being `expected` */
+pub fn parse_token<T: FromStr>(
+    line: usize,
+    line_text: &str,
+    token: &str,
+    expected: &str,
+) -> Result<T, ParseError> {
+    token
+        .parse::<T>()
+        .map_err(|_| ParseError::at_token(line, line_text, token, expected))
+}
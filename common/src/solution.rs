@@ -0,0 +1,59 @@
+use crate::ParseError;
+use std::fmt::Display;
+
+/** A puzzle answer, either a number or some text (e.g. letters drawn on a screen) */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Number(i64),
+    Text(String),
+    /** the input has no answer for this part, for the given reason */
+    Unavailable(String),
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Number(value) => write!(f, "{}", value),
+            Answer::Text(value) => write!(f, "{}", value),
+            Answer::Unavailable(reason) => write!(f, "no answer, {}", reason),
+        }
+    }
+}
+
+macro_rules! answer_from_int {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(value: $t) -> Self {
+                    Answer::Number(value as i64)
+                }
+            }
+        )*
+    };
+}
+
+answer_from_int!(i32, i64, isize, u32, usize);
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_string())
+    }
+}
+
+/**
+ * A day of the calendar: the puzzle input is parsed once,
+ * then both parts are solved from the same parsed input.
+ */
+pub trait Solution {
+    type Input;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+    fn part1(input: &Self::Input) -> Answer;
+    fn part2(input: &Self::Input) -> Answer;
+}
@@ -0,0 +1,84 @@
+use crate::{normalize, Answer, InputSource, Solution};
+use std::{fs, path::Path};
+
+fn parse_or_panic<S: Solution>(source_name: &str, input: &str) -> S::Input {
+    match S::parse(input) {
+        Ok(parsed) => parsed,
+        Err(error) => panic!("\n{}", error.render(source_name, input)),
+    }
+}
+
+fn solve<S: Solution>(parsed: &S::Input, part: u8) -> Answer {
+    match part {
+        1 => S::part1(parsed),
+        2 => S::part2(parsed),
+        _ => panic!("there is no part {}", part),
+    }
+}
+
+/** asserts the answer of one part of `S` on `input` */
+pub fn assert_answer<S: Solution>(input: &str, part: u8, expected: impl Into<Answer>) {
+    let input = normalize(input);
+    let parsed = parse_or_panic::<S>("<example>", &input);
+    assert_eq!(solve::<S>(&parsed, part), expected.into(), "part {}", part);
+}
+
+/** asserts both answers of `S` on `input`, typically a puzzle example */
+pub fn assert_answers<S: Solution>(
+    input: &str,
+    part1: impl Into<Answer>,
+    part2: impl Into<Answer>,
+) {
+    let input = normalize(input);
+    let parsed = parse_or_panic::<S>("<example>", &input);
+    assert_eq!(S::part1(&parsed), part1.into(), "part 1");
+    assert_eq!(S::part2(&parsed), part2.into(), "part 2");
+}
+
+/**
+ * Asserts the answers listed in `<day_dir>/answers.toml` for `<day_dir>/input`:
+ *
+ * ```toml
+ * part1 = 24000
+ * part2 = "CMZ"
+ * ```
+ *
+ * Either part may be left out, and nothing is checked when there is no such file.
+ */
+pub fn assert_answers_file<S: Solution>(day_dir: &str) {
+    let answers_path = Path::new(day_dir).join("answers.toml");
+    let Ok(content) = fs::read_to_string(&answers_path) else {
+        return;
+    };
+    let answers: toml::Table = content
+        .parse()
+        .unwrap_or_else(|error| panic!("{}: {}", answers_path.display(), error));
+
+    let source = InputSource::File(Path::new(day_dir).join("input"));
+    let input = source.read().unwrap_or_else(|error| panic!("{}", error));
+    let parsed = parse_or_panic::<S>(&source.to_string(), &input);
+
+    for part in [1, 2] {
+        let key = format!("part{}", part);
+        match answers.get(&key) {
+            Some(toml::Value::Integer(expected)) => {
+                assert_eq!(
+                    solve::<S>(&parsed, part),
+                    Answer::Number(*expected),
+                    "{}",
+                    key
+                )
+            }
+            Some(toml::Value::String(expected)) => {
+                assert_eq!(solve::<S>(&parsed, part).to_string(), *expected, "{}", key)
+            }
+            Some(other) => panic!(
+                "{}: `{}` must be a number or a string, found {}",
+                answers_path.display(),
+                key,
+                other
+            ),
+            None => (),
+        }
+    }
+}
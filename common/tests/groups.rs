@@ -0,0 +1,36 @@
+use common::{groups, parse_groups, Group};
+
+#[test]
+fn last_group_without_trailing_blank_line() {
+    let found: Vec<Group> = groups("1\n2\n\n3").collect();
+    assert_eq!(
+        found,
+        vec![
+            Group {
+                first_line: 1,
+                lines: vec!["1", "2"]
+            },
+            Group {
+                first_line: 4,
+                lines: vec!["3"]
+            },
+        ]
+    );
+}
+
+#[test]
+fn repeated_blank_lines_separate_once() {
+    let found: Vec<usize> = groups("\n1\n\n\n\n2\n\n").map(|g| g.first_line).collect();
+    assert_eq!(found, vec![2, 6]);
+}
+
+#[test]
+fn bad_line_is_located() {
+    let error = parse_groups::<i32>("1\n2\n\n3\nx4", "a number").unwrap_err();
+    assert_eq!((error.line, error.column), (5, 1));
+    assert_eq!(error.found, "x4");
+    assert_eq!(
+        parse_groups::<i32>("1\n2\n\n3", "a number"),
+        Ok(vec![vec![1, 2], vec![3]])
+    );
+}
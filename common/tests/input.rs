@@ -0,0 +1,55 @@
+use common::{normalize, InputSource};
+use std::{fs, io::Read, path::PathBuf};
+
+#[test]
+fn crlf_and_trailing_newlines() {
+    assert_eq!(normalize("1\r\n2\r\n\r\n3\r\n\r\n"), "1\n2\n\n3");
+    assert_eq!(normalize("1\n\n2\n\n\n"), "1\n\n2");
+    assert_eq!(normalize("\n\n1"), "\n\n1");
+    assert_eq!(normalize("a\rb"), "a\rb");
+    assert_eq!(normalize("\n"), "");
+    assert_eq!(normalize(""), "");
+}
+
+#[test]
+fn text_is_read_normalized_and_opened_raw() {
+    let source = InputSource::Text("A Y\r\nB X\r\n\r\n".to_string());
+    assert_eq!(source.read().unwrap(), "A Y\nB X");
+    let mut raw = String::new();
+    source.open().unwrap().read_to_string(&mut raw).unwrap();
+    assert_eq!(raw, "A Y\r\nB X\r\n\r\n");
+    assert_eq!(source.to_string(), "<text>");
+}
+
+#[test]
+fn sources_from_arguments() {
+    assert_eq!(InputSource::from_arg("-"), InputSource::Stdin);
+    assert_eq!(InputSource::Stdin.to_string(), "<stdin>");
+    let file = InputSource::from_arg("01-first/input");
+    assert_eq!(file, InputSource::File(PathBuf::from("01-first/input")));
+    assert_eq!(file.to_string(), "01-first/input");
+}
+
+#[test]
+fn files_are_read_normalized() {
+    let path = std::env::temp_dir().join(format!("common-input-{}", std::process::id()));
+    fs::write(&path, "1000\r\n2000\r\n").unwrap();
+    let read = InputSource::File(path.clone()).read();
+    fs::remove_file(&path).unwrap();
+    assert_eq!(read.unwrap(), "1000\n2000");
+}
+
+#[test]
+fn missing_file_errors_carry_the_path() {
+    let path = PathBuf::from("does/not/exist");
+    for error in [
+        InputSource::File(path.clone()).read().unwrap_err(),
+        InputSource::File(path.clone()).open().err().unwrap(),
+    ] {
+        assert_eq!(error.path, path);
+        assert_eq!(error.error.kind(), std::io::ErrorKind::NotFound);
+        assert!(error
+            .to_string()
+            .starts_with("cannot read does/not/exist: "));
+    }
+}
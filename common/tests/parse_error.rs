@@ -0,0 +1,63 @@
+use common::{column_of, parse_token, ParseError};
+
+#[test]
+fn mid_line_token() {
+    let input = "1000\n2000 3000\n4000";
+    let line = input.lines().nth(1).unwrap();
+    let error = ParseError::at_token(2, line, &line[5..], "end of line");
+    assert_eq!((error.line, error.column), (2, 6));
+    assert_eq!(
+        error.render("input", input),
+        "error: expected end of line, found `3000`\n \
+         --> input:2:6\n  \
+         |\n\
+         2 | 2000 3000\n  \
+         |      ^^^^"
+    );
+    assert_eq!(error.to_string(), "2:6: expected end of line, found `3000`");
+}
+
+#[test]
+fn end_of_line() {
+    let input = "A Y\nB";
+    let error = ParseError::at_end_of_line(2, "B", "a space then `X`, `Y` or `Z`");
+    assert_eq!(
+        error.render("<stdin>", input),
+        "error: expected a space then `X`, `Y` or `Z`, found end of line\n \
+         --> <stdin>:2:2\n  \
+         |\n\
+         2 | B\n  \
+         |  ^"
+    );
+}
+
+#[test]
+fn non_ascii_line() {
+    let line = "héllo wörld";
+    let token = &line[line.find('w').unwrap()..];
+    assert_eq!(column_of(line, token), 7);
+    let error = parse_token::<i32>(1, line, token, "a number").unwrap_err();
+    assert_eq!(
+        error.render("input", line),
+        "error: expected a number, found `wörld`\n \
+         --> input:1:7\n  \
+         |\n\
+         1 | héllo wörld\n  \
+         |       ^^^^^"
+    );
+}
+
+#[test]
+fn past_the_last_line() {
+    let error = ParseError::new(12, 1, "2 more rows", "");
+    let rendered = error.render("input", "123\n456");
+    assert_eq!(
+        rendered,
+        "error: expected 2 more rows, found end of line\n  \
+         --> input:12:1\n   \
+         |\n\
+         12 | \n   \
+         | ^"
+    );
+}
+